@@ -0,0 +1,354 @@
+use magiceye::{
+    config::Config,
+    platform_specific::{get_config, get_editable_config, save_config},
+    sql::secret::redact_url,
+};
+use serde_json::Value;
+
+use crate::command::config::ConfigAction;
+
+use super::{
+    database_summary,
+    run::{check_side, DatabaseSide},
+    EXIT_ERROR, EXIT_SUCCESS,
+};
+
+pub async fn execute(action: ConfigAction) -> i32 {
+    log::info!("execute action: config");
+
+    // 수정하는 명령은 저장할 설정 파일만, 조회하는 명령은 합쳐진 설정을 읽습니다.
+    let config = match action {
+        ConfigAction::Set(_) | ConfigAction::RemovePair(_) => get_editable_config(),
+        _ => get_config(),
+    };
+
+    let config = match config {
+        Ok(config) => config,
+        Err(error) => {
+            println!("failed to get config: {:?}", error);
+            return EXIT_ERROR;
+        }
+    };
+
+    log::debug!("action: {:?}", action);
+
+    let result = match action {
+        ConfigAction::List => {
+            list(&config);
+            Ok(())
+        }
+        ConfigAction::Show(flags) => show(&config, flags.name.as_deref()),
+        ConfigAction::Set(flags) => set(config, &flags.path, &flags.value),
+        ConfigAction::RemovePair(flags) => remove_pair(config, &flags.name),
+        ConfigAction::Validate(flags) => validate(&config, flags.offline).await,
+    };
+
+    match result {
+        Ok(()) => EXIT_SUCCESS,
+        Err(error) => {
+            println!("{error}");
+            EXIT_ERROR
+        }
+    }
+}
+
+// 기본 쌍은 *로 표시합니다.
+fn list(config: &Config) {
+    let default_pair_name = config
+        .default_database_pair
+        .as_ref()
+        .map(|pair| pair.name.as_str());
+
+    println!("Database pairs:");
+
+    for pair in config.all_pairs() {
+        let marker = if Some(pair.name.as_str()) == default_pair_name {
+            "*"
+        } else {
+            " "
+        };
+
+        println!(
+            "{marker} {} ({:?}) {} -> {}",
+            pair.name,
+            pair.database_type,
+            redact_url(&pair.base_connection),
+            redact_url(&pair.target_connection)
+        );
+    }
+
+    if config.comparison_groups.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("Comparison groups:");
+
+    for group in &config.comparison_groups {
+        let targets: Vec<&str> = group
+            .targets
+            .iter()
+            .map(|target| target.name.as_str())
+            .collect();
+
+        println!(
+            "  {} ({:?}) {} -> {}",
+            group.name,
+            group.database_type,
+            redact_url(&group.base_connection),
+            targets.join(", ")
+        );
+    }
+}
+
+fn show(config: &Config, name: Option<&str>) -> anyhow::Result<()> {
+    let mut value = match name {
+        None => serde_json::to_value(config)?,
+        Some(name) => {
+            if let Some(pair) = config.find_pair(name) {
+                serde_json::to_value(pair)?
+            } else if let Some(group) = config.find_group(name) {
+                serde_json::to_value(group)?
+            } else {
+                return Err(anyhow::anyhow!("pair or group [{name}] is not found."));
+            }
+        }
+    };
+
+    redact_connections(&mut value);
+
+    println!("{}", serde_json::to_string_pretty(&value)?);
+
+    Ok(())
+}
+
+// 이름이 connection으로 끝나는 모든 값(base_connection, target_connection 등)의 비밀번호를 가립니다.
+fn redact_connections(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                match item {
+                    Value::String(url) if key.ends_with("connection") => *url = redact_url(url),
+                    _ => redact_connections(item),
+                }
+            }
+        }
+        Value::Array(list) => list.iter_mut().for_each(redact_connections),
+        _ => {}
+    }
+}
+
+// 설정을 JSON으로 바꾼 뒤, 경로의 값을 바꾸고 다시 Config로 읽어서 검증합니다.
+fn set(config: Config, path: &str, value: &str) -> anyhow::Result<()> {
+    let segments: Vec<&str> = path.split('.').collect();
+
+    // 값이 JSON이 아니라면(예: URL, 이름) 문자열로 봅니다.
+    let new_value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.into()));
+
+    // 기본 쌍은 이름으로 선택할 수 있습니다. (예: set default_database_pair prod)
+    let new_value = match (path, &new_value) {
+        ("default_database_pair", Value::String(name)) => match config.find_pair(name) {
+            Some(pair) => serde_json::to_value(pair)?,
+            None => return Err(anyhow::anyhow!("database pair [{name}] is not found.")),
+        },
+        _ => new_value,
+    };
+
+    let mut root = serde_json::to_value(&config)?;
+
+    let Some((last, parents)) = segments.split_last() else {
+        return Err(anyhow::anyhow!("path is empty."));
+    };
+
+    let mut parent = &mut root;
+
+    for segment in parents {
+        parent = match find_child(parent, segment) {
+            Some(child) => child,
+            None => return Err(anyhow::anyhow!("[{path}] is not found.")),
+        };
+    }
+
+    match parent {
+        Value::Object(map) => {
+            map.insert(last.to_string(), new_value.clone());
+        }
+        Value::Array(_) => match find_child(parent, last) {
+            Some(child) => *child = new_value.clone(),
+            None => return Err(anyhow::anyhow!("[{path}] is not found.")),
+        },
+        _ => return Err(anyhow::anyhow!("[{path}] is not found.")),
+    }
+
+    let mut new_config: Config = match serde_json::from_value(root) {
+        Ok(config) => config,
+        Err(error) => return Err(anyhow::anyhow!("invalid value for [{path}]: {error}")),
+    };
+
+    // 모르는 키는 읽는 과정에서 버려지므로, 다시 JSON으로 바꿨을 때 키가 남아 있는지 확인합니다.
+    // 객체 값은 기본값이 채워질 수 있으므로, 넣은 키가 모두 남아 있는지만 확인합니다.
+    let mut saved = serde_json::to_value(&new_config)?;
+
+    if !find_path(&mut saved, &segments).is_some_and(|saved| has_all_keys(&new_value, saved)) {
+        return Err(anyhow::anyhow!("[{path}] is not a known config key."));
+    }
+
+    // database_pairs와 default_database_pair에 같은 쌍이 있다면, 바꾼 쪽의 값으로 맞춥니다.
+    if let Some(default_pair) = new_config.default_database_pair.clone() {
+        if segments[0] == "default_database_pair" {
+            if new_config.find_pair(&default_pair.name).is_some() {
+                new_config.upsert_pair(default_pair);
+            }
+        } else if let Some(pair) = new_config
+            .database_pairs
+            .iter()
+            .find(|pair| pair.name == default_pair.name)
+        {
+            new_config.default_database_pair = Some(pair.clone());
+        }
+    }
+
+    save_config(&new_config)?;
+
+    // 출력할 때는 비밀번호를 가립니다.
+    let printed_value = match new_value {
+        Value::String(url) if path.ends_with("connection") => Value::String(redact_url(&url)),
+        mut value => {
+            redact_connections(&mut value);
+            value
+        }
+    };
+
+    println!("{path} = {printed_value}");
+
+    Ok(())
+}
+
+// 객체는 키로, 배열은 name 또는 순서로 찾습니다.
+fn find_child<'a>(value: &'a mut Value, segment: &str) -> Option<&'a mut Value> {
+    match value {
+        Value::Object(map) => map.get_mut(segment),
+        Value::Array(list) => {
+            let position = list
+                .iter()
+                .position(|item| item.get("name").and_then(Value::as_str) == Some(segment))
+                .or_else(|| segment.parse().ok())?;
+
+            list.get_mut(position)
+        }
+        _ => None,
+    }
+}
+
+fn has_all_keys(value: &Value, saved: &Value) -> bool {
+    match (value, saved) {
+        (Value::Object(map), Value::Object(saved_map)) => map.iter().all(|(key, item)| {
+            saved_map
+                .get(key)
+                .is_some_and(|saved_item| has_all_keys(item, saved_item))
+        }),
+        (Value::Object(_), _) => false,
+        _ => true,
+    }
+}
+
+fn find_path<'a>(value: &'a mut Value, segments: &[&str]) -> Option<&'a mut Value> {
+    segments
+        .iter()
+        .try_fold(value, |value, segment| find_child(value, segment))
+}
+
+fn remove_pair(mut config: Config, name: &str) -> anyhow::Result<()> {
+    let pair_count = config.database_pairs.len();
+    config.database_pairs.retain(|pair| pair.name != name);
+
+    let is_default = config
+        .default_database_pair
+        .as_ref()
+        .is_some_and(|pair| pair.name == name);
+
+    if pair_count == config.database_pairs.len() && !is_default {
+        return Err(anyhow::anyhow!("database pair [{name}] is not found."));
+    }
+
+    if is_default {
+        config.default_database_pair = None;
+    }
+
+    save_config(&config)?;
+
+    println!("database pair [{name}] removed.");
+
+    if is_default {
+        println!("it was the default pair. select another one with [magiceye init --pair <name>].");
+    }
+
+    Ok(())
+}
+
+async fn validate(config: &Config, offline: bool) -> anyhow::Result<()> {
+    let problems = config.validate();
+
+    if !problems.is_empty() {
+        for problem in &problems {
+            println!("  - {problem}");
+        }
+
+        return Err(anyhow::anyhow!("{} problems found.", problems.len()));
+    }
+
+    println!("Config is valid.");
+
+    if offline {
+        return Ok(());
+    }
+
+    // 모든 쌍과 그룹의 커넥션에 실제로 연결해봅니다.
+    let mut connections = vec![];
+
+    for pair in config.all_pairs() {
+        connections.push((
+            format!("{} base", pair.name),
+            pair.clone(),
+            DatabaseSide::Base,
+        ));
+        connections.push((format!("{} target", pair.name), pair, DatabaseSide::Target));
+    }
+
+    for group in &config.comparison_groups {
+        connections.push((
+            format!("{} base", group.name),
+            group.base_pair(),
+            DatabaseSide::Base,
+        ));
+
+        for target in &group.targets {
+            connections.push((
+                format!("{}.{}", group.name, target.name),
+                group.target_pair(target),
+                DatabaseSide::Target,
+            ));
+        }
+    }
+
+    println!();
+    println!("Connections:");
+
+    let mut failure_count = 0;
+
+    for (label, pair, side) in connections {
+        match check_side(&pair, side).await {
+            Ok(database) => println!("  ok     {label}: {}", database_summary(&database)),
+            Err(error) => {
+                failure_count += 1;
+                println!("  failed {label}: {error}");
+            }
+        }
+    }
+
+    if failure_count > 0 {
+        return Err(anyhow::anyhow!("{failure_count} connections failed."));
+    }
+
+    Ok(())
+}
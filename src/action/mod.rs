@@ -1,3 +1,4 @@
+pub(crate) mod config;
 pub(crate) mod init;
 pub(crate) mod run;
 
@@ -10,8 +11,17 @@ use crossterm::ExecutableCommand;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 
+use magiceye::sql::Database;
+
 type TerminalType = Terminal<CrosstermBackend<Stdout>>;
 
+// 프로세스 종료 코드
+pub const EXIT_SUCCESS: i32 = 0;
+// --ci 실행에서 fail_on 이상의 차이가 있음
+pub const EXIT_FAILURE: i32 = 1;
+// 연결 실패, 잘못된 설정 등으로 작업을 끝내지 못함
+pub const EXIT_ERROR: i32 = 2;
+
 pub fn enter_tui() -> TerminalType {
     stdout().execute(EnterAlternateScreen).unwrap();
     enable_raw_mode().unwrap();
@@ -25,3 +35,15 @@ pub fn exit_tui() {
     stdout().execute(LeaveAlternateScreen).unwrap();
     disable_raw_mode().unwrap();
 }
+
+// 연결 확인 결과를 한 줄로 보여줍니다. (예: prod (PostgreSQL 16.2))
+pub fn database_summary(database: &Database) -> String {
+    // PostgreSQL의 version()은 빌드 정보까지 포함하므로 앞부분만 보여줍니다.
+    let version = database.version.split(" on ").next().unwrap_or_default();
+
+    if version.is_empty() {
+        database.name.clone()
+    } else {
+        format!("{} ({version})", database.name)
+    }
+}
@@ -1,15 +1,25 @@
 mod background;
+mod output;
+mod plain;
 mod tui;
 
 use std::sync::mpsc::{channel, Receiver, Sender};
 use tui::ProgressEvent;
 
-use crate::{
-    command::run::CommandFlags,
-    config::{Config, DatabasePair},
+// init과 config validate에서 연결을 확인할 때 사용합니다.
+pub(crate) use background::check_side;
+pub(crate) use tui::DatabaseSide;
+
+use magiceye::{
+    config::{ComparisonGroup, Config, DatabasePair},
     platform_specific::get_config,
+    report::ReportSchema,
 };
 
+use crate::command::run::CommandFlags;
+
+use super::{EXIT_ERROR, EXIT_SUCCESS};
+
 pub struct ReceiverContext {
     event_receiver: Receiver<ProgressEvent>,
 }
@@ -18,16 +28,17 @@ pub struct SenderContext {
     event_sender: Sender<ProgressEvent>,
     config: Config,
     database_pair: DatabasePair,
+    flags: CommandFlags,
 }
 
-pub async fn execute(flags: CommandFlags) {
+pub async fn execute(flags: CommandFlags) -> i32 {
     log::info!("execute action: run");
 
     let config = match get_config() {
         Ok(config) => config,
         Err(error) => {
             println!("failed to get config: {:?}", error);
-            return;
+            return EXIT_ERROR;
         }
     };
 
@@ -35,31 +46,211 @@ pub async fn execute(flags: CommandFlags) {
 
     log::debug!("flags: {:?}", flags);
 
-    // 1. 커넥션 정보가 없다면 에러 문구를 출력하고 종료합니다.
-    let Some(database_pair) = config.default_database_pair.clone() else {
+    // 비교 그룹이라면 별도로 처리합니다. 보고서는 하나입니다.
+    if let Some(group_name) = flags.group.clone() {
+        let Some(group) = config.find_group(&group_name).cloned() else {
+            println!("comparison group [{group_name}] is not found. check comparison_groups in the config file.");
+            return EXIT_ERROR;
+        };
+
+        if let Err(error) = output::check_output_flags(&flags, 1)
+            .and_then(|_| background::check_baseline_flags(&flags, &config, group.targets.len()))
+        {
+            println!("{error}");
+            return EXIT_ERROR;
+        }
+
+        return execute_group(config, group, flags).await;
+    }
+
+    // 1. 비교할 데이터베이스 쌍을 선택합니다. 커넥션 정보가 없다면 에러 문구를 출력하고 종료합니다.
+    let database_pairs = if flags.all_pairs {
+        config.all_pairs()
+    } else if let Some(pair_name) = &flags.pair {
+        match config.find_pair(pair_name) {
+            Some(database_pair) => vec![database_pair.clone()],
+            None => {
+                println!("database pair [{pair_name}] is not found. try to [magiceye init] first.");
+                return EXIT_ERROR;
+            }
+        }
+    } else {
+        config.default_database_pair.clone().into_iter().collect()
+    };
+
+    if database_pairs.is_empty() {
         println!("database connection pair is not set. try to [magiceye init] first.");
-        return;
+        return EXIT_ERROR;
+    }
+
+    if let Err(error) = output::check_output_flags(&flags, database_pairs.len())
+        .and_then(|_| background::check_baseline_flags(&flags, &config, database_pairs.len()))
+    {
+        println!("{error}");
+        return EXIT_ERROR;
+    }
+
+    let (sender, receiver) = channel();
+
+    let ci = flags.ci;
+    let output_flags = flags.clone();
+    let to_stdout = output::is_stdout(&flags);
+
+    // 2. 실질적인 작업은 백그라운드 스레드로 작업합니다. 여러 쌍이라면 차례로 비교합니다.
+    let handle = tokio::spawn(async move {
+        let mut results = vec![];
+
+        for database_pair in database_pairs {
+            _ = sender.send(ProgressEvent::StartPair(database_pair.name.clone()));
+
+            let pair_name = database_pair.name.clone();
+
+            let result = background::generate_report(SenderContext {
+                event_sender: sender.clone(),
+                config: config.clone(),
+                database_pair,
+                flags: flags.clone(),
+            })
+            .await;
+
+            // 한 쌍이 실패해도 나머지 쌍은 계속 비교합니다.
+            if let Err(error) = &result {
+                _ = sender.send(ProgressEvent::Error(format!("[{pair_name}] {error:?}")));
+            }
+
+            results.push((pair_name, result));
+        }
+
+        _ = sender.send(ProgressEvent::Finished);
+
+        results
+    });
+
+    let receiver_context = ReceiverContext {
+        event_receiver: receiver,
     };
 
+    // 3. --ci라면 진행상황을 로그로 출력하고, 결과를 요약한 뒤 종료 코드를 반환합니다.
+    if ci {
+        plain::run_progress_log(receiver_context, to_stdout);
+
+        let Ok(results) = handle.await else {
+            return EXIT_ERROR;
+        };
+
+        // 종료 코드는 가장 나쁜 쌍의 결과를 따릅니다.
+        let mut exit_code = EXIT_SUCCESS;
+
+        for (pair_name, result) in &results {
+            let pair_exit_code = plain::print_pair_summary(pair_name, result, to_stdout);
+
+            exit_code = exit_code.max(pair_exit_code);
+        }
+
+        if to_stdout {
+            exit_code = exit_code.max(print_reports(&output_flags, &results));
+        }
+
+        return exit_code;
+    }
+
+    // 4. 진행상황은 TUI 기반으로 확인할 수 있게 합니다.
+    if let Err(error) = tui::run_progress_view(receiver_context) {
+        println!("failed to run progress view: {:?}", error);
+    }
+
+    // 5. --output -라면 TUI를 닫은 뒤 보고서를 출력합니다. 작업이 끝나기 전에 닫았다면 출력하지 않습니다.
+    if to_stdout && handle.is_finished() {
+        if let Ok(results) = handle.await {
+            return print_reports(&output_flags, &results);
+        }
+    }
+
+    EXIT_SUCCESS
+}
+
+// --output -일 때, 생성된 보고서를 표준 출력에 씁니다.
+fn print_reports(flags: &CommandFlags, results: &[(String, anyhow::Result<ReportSchema>)]) -> i32 {
+    for (pair_name, result) in results {
+        if let Ok(report) = result {
+            if let Err(error) = output::print_report(flags, report) {
+                eprintln!("failed to print report of [{pair_name}]: {error}");
+                return EXIT_ERROR;
+            }
+        }
+    }
+
+    EXIT_SUCCESS
+}
+
+// 하나의 base를 여러 target 환경과 비교합니다.
+async fn execute_group(config: Config, group: ComparisonGroup, flags: CommandFlags) -> i32 {
     let (sender, receiver) = channel();
 
-    // 2. 실질적인 작업은 백그라운드 스레드로 작업합니다.
-    tokio::spawn(async move {
-        if let Err(error) = background::generate_report(SenderContext {
-            event_sender: sender.clone(),
-            config: config.clone(),
-            database_pair: database_pair.clone(),
-        })
-        .await
-        {
+    let ci = flags.ci;
+    let output_flags = flags.clone();
+    let to_stdout = output::is_stdout(&flags);
+
+    // 1. 실질적인 작업은 백그라운드 스레드로 작업합니다.
+    let handle = tokio::spawn(async move {
+        let result = background::generate_matrix_report(
+            SenderContext {
+                event_sender: sender.clone(),
+                config,
+                database_pair: group.base_pair(),
+                flags,
+            },
+            group,
+        )
+        .await;
+
+        if let Err(error) = &result {
             _ = sender.send(ProgressEvent::Error(format!("{error:?}")));
         }
+
+        _ = sender.send(ProgressEvent::Finished);
+
+        result
     });
 
-    // 3. 진행상황은 TUI 기반으로 확인할 수 있게 합니다.
-    if let Err(error) = tui::run_progress_view(ReceiverContext {
+    let receiver_context = ReceiverContext {
         event_receiver: receiver,
-    }) {
+    };
+
+    // 2. --ci라면 진행상황을 로그로 출력하고, 결과를 요약한 뒤 종료 코드를 반환합니다.
+    if ci {
+        plain::run_progress_log(receiver_context, to_stdout);
+
+        let Ok(Ok(report)) = handle.await else {
+            return EXIT_ERROR;
+        };
+
+        let exit_code = plain::print_matrix_summary(&report, to_stdout);
+
+        if to_stdout {
+            if let Err(error) = output::print_report(&output_flags, &report) {
+                eprintln!("failed to print report: {error}");
+                return EXIT_ERROR;
+            }
+        }
+
+        return exit_code;
+    }
+
+    // 3. 진행상황은 TUI 기반으로 확인할 수 있게 합니다.
+    if let Err(error) = tui::run_progress_view(receiver_context) {
         println!("failed to run progress view: {:?}", error);
     }
+
+    // 4. --output -라면 TUI를 닫은 뒤 보고서를 출력합니다.
+    if to_stdout && handle.is_finished() {
+        if let Ok(Ok(report)) = handle.await {
+            if let Err(error) = output::print_report(&output_flags, &report) {
+                eprintln!("failed to print report: {error}");
+                return EXIT_ERROR;
+            }
+        }
+    }
+
+    EXIT_SUCCESS
 }
@@ -1,27 +1,46 @@
 #[path = "./steps/mod.rs"]
 mod steps;
 
-use super::{tui::ProgressEvent, SenderContext};
+pub(super) use steps::check_baseline_flags;
+pub(crate) use steps::check_side;
 
-pub(super) async fn generate_report(context: SenderContext) -> anyhow::Result<()> {
+use std::collections::HashMap;
+
+use magiceye::{
+    config::ComparisonGroup,
+    report::{matrix::MatrixReport, ReportSchema},
+    sql::Table,
+};
+
+use super::{
+    output,
+    tui::{DatabaseSide, FetchingTableList, ProgressEvent},
+    SenderContext,
+};
+
+pub(super) async fn generate_report(context: SenderContext) -> anyhow::Result<ReportSchema> {
     // 1. 커넥션 정보를 기반으로 실제 데이터베이스에 연결합니다.
     _ = context.event_sender.send(ProgressEvent::Start);
-    let (base_connection_pool, target_connection_pool) =
-        match steps::connect_database(&context.database_pair).await {
-            Ok(pools) => pools,
-            Err(error) => {
-                return Err(anyhow::anyhow!(
-                    "failed to connect to database: {:?}",
-                    error
-                ));
-            }
-        };
+    let (base_source, target_source) = match steps::connect_database(&context.database_pair).await {
+        Ok(sources) => sources,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "failed to connect to database: {:?}",
+                error
+            ));
+        }
+    };
 
-    // 2. base 테이블 목록을 조회합니다.
+    // 2. base와 target의 테이블 목록을 동시에 조회합니다.
     _ = context
         .event_sender
-        .send(ProgressEvent::StartFetchingBaseTableList);
-    let base_table_map = match steps::get_table_list(&context, &base_connection_pool).await {
+        .send(ProgressEvent::StartFetchingTableList);
+    let (base_table_map, target_table_map) = tokio::join!(
+        steps::get_table_list(&context, DatabaseSide::Base, base_source.as_ref()),
+        steps::get_table_list(&context, DatabaseSide::Target, target_source.as_ref())
+    );
+
+    let base_table_map = match base_table_map {
         Ok(map) => map,
         Err(error) => {
             return Err(anyhow::anyhow!(
@@ -31,11 +50,7 @@ pub(super) async fn generate_report(context: SenderContext) -> anyhow::Result<()
         }
     };
 
-    // 3. 대상 테이블 목록을 조회합니다.
-    _ = context
-        .event_sender
-        .send(ProgressEvent::StartFetchingTargetTableList);
-    let target_table_map = match steps::get_table_list(&context, &target_connection_pool).await {
+    let target_table_map = match target_table_map {
         Ok(map) => map,
         Err(error) => {
             return Err(anyhow::anyhow!(
@@ -45,27 +60,156 @@ pub(super) async fn generate_report(context: SenderContext) -> anyhow::Result<()
         }
     };
 
-    // 4. base 테이블을 기준점으로 삼아서, target 테이블과 비교합니다.
+    // 3. base 테이블을 기준점으로 삼아서, target 테이블과 비교합니다.
     // A. base에 있는데 target에 없는 것은 보고 대상입니다.
     // B. base에 있고 target에도 있지만, 내용이 다른 것도 보고 대상입니다.
-    // C. base에 없고 target에만 있는 것은 보고 대상이 아닙니다. 무시합니다.
+    // C. base에 없고 target에만 있는 것은 bidirectional 옵션이 켜져 있을 때만 보고합니다.
     _ = context
         .event_sender
         .send(ProgressEvent::StartComparingTable);
 
-    let report = steps::difference_check(&context, base_table_map, target_table_map);
+    let mut report = steps::difference_check(&context, base_table_map, target_table_map);
+
+    // 4. 조회 중에 마이그레이션 등으로 스키마가 변경되었다면 보고서에 경고를 남깁니다.
+    let (base_warning, target_warning) = tokio::join!(
+        steps::check_schema_change(&context, DatabaseSide::Base, base_source.as_ref()),
+        steps::check_schema_change(&context, DatabaseSide::Target, target_source.as_ref())
+    );
+
+    report.warnings.extend(base_warning);
+    report.warnings.extend(target_warning);
+
+    // 5. baseline 파일에 기록된, 이미 확인한 차이를 표시합니다.
+    if let Err(error) = steps::apply_baseline(&context, &mut report) {
+        return Err(anyhow::anyhow!("failed to apply baseline: {:?}", error));
+    }
+
+    // 6. 보고서를 파일로 생성합니다. --output -라면 작업이 끝난 뒤 표준 출력에 씁니다.
+    _ = context.event_sender.send(ProgressEvent::SavingReportFile);
+
+    if let Err(error) = output::save_report(&context.flags, &context.database_pair.name, &report) {
+        return Err(anyhow::anyhow!("failed to save report: {error}"));
+    }
+
+    Ok(report)
+}
+
+// 하나의 base를 여러 target 환경과 비교합니다.
+// base는 한 번만 조회하고, 환경별 보고서를 모아서 매트릭스 보고서를 생성합니다.
+pub(super) async fn generate_matrix_report(
+    context: SenderContext,
+    group: ComparisonGroup,
+) -> anyhow::Result<MatrixReport> {
+    // 1. base에 연결하고 테이블 목록을 한 번만 조회합니다.
+    _ = context
+        .event_sender
+        .send(ProgressEvent::StartPair(format!("{} (base)", group.name)));
+    _ = context.event_sender.send(ProgressEvent::Start);
+
+    let base_source = steps::connect_side(&context.database_pair, DatabaseSide::Base).await?;
+
+    _ = context
+        .event_sender
+        .send(ProgressEvent::StartFetchingTableList);
+    let base_table_map =
+        match steps::get_table_list(&context, DatabaseSide::Base, base_source.as_ref()).await {
+            Ok(map) => map,
+            Err(error) => {
+                return Err(anyhow::anyhow!(
+                    "failed to get base table list: {:?}",
+                    error
+                ));
+            }
+        };
+
+    // 2. target 환경마다 비교합니다. 한 환경이 실패해도 나머지 환경은 계속 비교합니다.
+    let mut reports = vec![];
+    let mut errors = vec![];
+
+    for target in &group.targets {
+        let target_context = SenderContext {
+            event_sender: context.event_sender.clone(),
+            config: context.config.clone(),
+            database_pair: group.target_pair(target),
+            flags: context.flags.clone(),
+        };
+
+        match compare_target(&target_context, &base_table_map).await {
+            Ok(report) => reports.push((target.name.clone(), report)),
+            Err(error) => {
+                _ = context
+                    .event_sender
+                    .send(ProgressEvent::Error(format!("[{}] {error:?}", target.name)));
+
+                errors.push(format!("{}: {error}", target.name));
+            }
+        }
+    }
 
-    // 5. 보고서를 파일로 생성합니다.
+    // 3. 환경별 보고서를 객체와 속성 기준으로 모읍니다.
+    let mut report = MatrixReport::new(group.name.clone(), reports, context.config.fail_on);
+    report.errors = errors;
+    report.warnings.extend(
+        steps::check_schema_change(&context, DatabaseSide::Base, base_source.as_ref()).await,
+    );
+
+    // 4. 보고서를 파일로 생성합니다.
     _ = context.event_sender.send(ProgressEvent::SavingReportFile);
 
-    let current_date = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
-    let report_file_name = format!("report_{}.json", current_date);
+    if let Err(error) = output::save_report(&context.flags, &group.name, &report) {
+        return Err(anyhow::anyhow!("failed to save report: {error}"));
+    }
+
+    Ok(report)
+}
+
+// 이미 조회한 base 테이블 목록을 target 환경 하나와 비교합니다.
+async fn compare_target(
+    context: &SenderContext,
+    base_table_map: &HashMap<String, Table>,
+) -> anyhow::Result<ReportSchema> {
+    _ = context
+        .event_sender
+        .send(ProgressEvent::StartPair(context.database_pair.name.clone()));
+    _ = context.event_sender.send(ProgressEvent::Start);
+
+    let target_source = steps::connect_side(&context.database_pair, DatabaseSide::Target).await?;
+
+    _ = context
+        .event_sender
+        .send(ProgressEvent::StartFetchingTableList);
+    _ = context
+        .event_sender
+        .send(ProgressEvent::FetchingTableList(FetchingTableList {
+            side: DatabaseSide::Base,
+            total_count: Some(base_table_map.len()),
+            current_count: base_table_map.len(),
+        }));
+
+    let target_table_map =
+        match steps::get_table_list(context, DatabaseSide::Target, target_source.as_ref()).await {
+            Ok(map) => map,
+            Err(error) => {
+                return Err(anyhow::anyhow!(
+                    "failed to get target table list: {:?}",
+                    error
+                ));
+            }
+        };
+
+    _ = context
+        .event_sender
+        .send(ProgressEvent::StartComparingTable);
 
-    let report_json = serde_json::to_string_pretty(&report).unwrap();
+    let mut report = steps::difference_check(context, base_table_map.clone(), target_table_map);
 
-    std::fs::write(&report_file_name, &report_json).unwrap();
+    report.warnings.extend(
+        steps::check_schema_change(context, DatabaseSide::Target, target_source.as_ref()).await,
+    );
 
-    _ = context.event_sender.send(ProgressEvent::Finished);
+    if let Err(error) = steps::apply_baseline(context, &mut report) {
+        return Err(anyhow::anyhow!("failed to apply baseline: {:?}", error));
+    }
 
-    Ok(())
+    Ok(report)
 }
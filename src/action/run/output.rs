@@ -0,0 +1,177 @@
+use std::path::PathBuf;
+
+use magiceye::report::format::{RenderReport, ReportFormat};
+
+use crate::command::run::CommandFlags;
+
+// 같은 분 안에 실행해도 덮어쓰지 않도록 초 단위까지 쓰고, 파일 이름에 쓸 수 없는 콜론은 쓰지 않습니다.
+const DEFAULT_FILE_NAME: &str = "report_{pair}_{timestamp}.{ext}";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+// --output -라면 보고서를 파일 대신 표준 출력에 씁니다.
+pub(super) fn is_stdout(flags: &CommandFlags) -> bool {
+    flags.output.as_deref() == Some("-")
+}
+
+// --format이 없다면 JSON으로 생성합니다. 같은 형식을 여러 번 지정해도 한 번만 생성합니다.
+fn formats(flags: &CommandFlags) -> Vec<ReportFormat> {
+    let mut formats = vec![];
+
+    for format in &flags.format {
+        if !formats.contains(format) {
+            formats.push(*format);
+        }
+    }
+
+    if formats.is_empty() {
+        formats.push(ReportFormat::default());
+    }
+
+    formats
+}
+
+// 비교를 시작하기 전에 출력 옵션을 확인합니다. report_count는 생성할 보고서 수(비교할 쌍의 수)입니다.
+pub(super) fn check_output_flags(flags: &CommandFlags, report_count: usize) -> anyhow::Result<()> {
+    let file_name = flags.output.as_deref().unwrap_or(DEFAULT_FILE_NAME);
+
+    if is_stdout(flags) {
+        if flags.output_dir.is_some() {
+            return Err(anyhow::anyhow!(
+                "--output-dir cannot be used with --output -"
+            ));
+        }
+
+        // 여러 보고서를 이어서 쓰면 하나의 JSON으로 읽을 수 없으므로, 하나만 허용합니다.
+        if report_count > 1 {
+            return Err(anyhow::anyhow!(
+                "--output - cannot be used when more than one database pair is compared (use --pair, or write files with --output-dir)"
+            ));
+        }
+
+        return Ok(());
+    }
+
+    // 쌍마다 다른 파일에 써야 하므로, 여러 쌍이라면 {pair}가 있어야 합니다.
+    if report_count > 1 && !file_name.contains("{pair}") {
+        return Err(anyhow::anyhow!(
+            "--output must contain {{pair}} when more than one database pair is compared"
+        ));
+    }
+
+    // 형식마다 다른 파일에 써야 하므로, 여러 형식이라면 {ext}가 있어야 합니다.
+    if formats(flags).len() > 1 && !file_name.contains("{ext}") {
+        return Err(anyhow::anyhow!(
+            "--output must contain {{ext}} when more than one --format is given"
+        ));
+    }
+
+    Ok(())
+}
+
+// --output-dir과 --output으로 정한 경로에, 형식마다 보고서 파일을 저장합니다.
+// --output -라면 아무것도 저장하지 않습니다.
+pub(super) fn save_report(
+    flags: &CommandFlags,
+    name: &str,
+    report: &impl RenderReport,
+) -> anyhow::Result<()> {
+    if is_stdout(flags) {
+        return Ok(());
+    }
+
+    let file_name = flags.output.as_deref().unwrap_or(DEFAULT_FILE_NAME);
+    let timestamp = chrono::Local::now().format(TIMESTAMP_FORMAT).to_string();
+
+    for format in formats(flags) {
+        let file_name = file_name
+            .replace("{pair}", name)
+            .replace("{timestamp}", &timestamp)
+            .replace("{ext}", format.extension());
+
+        let path = match &flags.output_dir {
+            Some(output_dir) => PathBuf::from(output_dir).join(file_name),
+            None => PathBuf::from(file_name),
+        };
+
+        let content = report.render(format)?;
+
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            if let Err(error) = std::fs::create_dir_all(parent) {
+                return Err(anyhow::anyhow!(
+                    "failed to create report directory {}: {error}",
+                    parent.display()
+                ));
+            }
+        }
+
+        if let Err(error) = std::fs::write(&path, content) {
+            return Err(anyhow::anyhow!(
+                "failed to write report file {}: {error}",
+                path.display()
+            ));
+        }
+
+        log::info!("report file saved: {}", path.display());
+    }
+
+    Ok(())
+}
+
+// --output -라면, 작업이 끝난 뒤 형식마다 보고서를 표준 출력에 씁니다.
+pub(super) fn print_report(flags: &CommandFlags, report: &impl RenderReport) -> anyhow::Result<()> {
+    for format in formats(flags) {
+        println!("{}", report.render(format)?);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flags(output: Option<&str>, format: &[ReportFormat]) -> CommandFlags {
+        CommandFlags {
+            output: output.map(str::to_string),
+            format: format.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn stdout_with_one_report() {
+        assert!(check_output_flags(&flags(Some("-"), &[]), 1).is_ok());
+
+        let mut output_dir_flags = flags(Some("-"), &[]);
+        output_dir_flags.output_dir = Some("reports".to_string());
+        assert!(check_output_flags(&output_dir_flags, 1).is_err());
+    }
+
+    #[test]
+    fn stdout_with_many_reports() {
+        assert!(check_output_flags(&flags(Some("-"), &[]), 2).is_err());
+    }
+
+    #[test]
+    fn file_name_needs_pair_with_many_reports() {
+        assert!(check_output_flags(&flags(None, &[]), 3).is_ok());
+        assert!(check_output_flags(&flags(Some("drift.json"), &[]), 1).is_ok());
+        assert!(check_output_flags(&flags(Some("drift.json"), &[]), 2).is_err());
+        assert!(check_output_flags(&flags(Some("drift_{pair}.json"), &[]), 2).is_ok());
+    }
+
+    #[test]
+    fn file_name_needs_ext_with_many_formats() {
+        let formats = [ReportFormat::Json, ReportFormat::Markdown];
+
+        assert!(check_output_flags(&flags(None, &formats), 1).is_ok());
+        assert!(check_output_flags(&flags(Some("drift_{pair}"), &formats), 1).is_err());
+        assert!(check_output_flags(&flags(Some("drift_{pair}.{ext}"), &formats), 1).is_ok());
+        // 같은 형식을 여러 번 지정한 것은 하나로 봅니다.
+        let formats = [ReportFormat::Json, ReportFormat::Json];
+        assert!(check_output_flags(&flags(Some("drift.json"), &formats), 1).is_ok());
+    }
+}
@@ -0,0 +1,257 @@
+use magiceye::report::{matrix::MatrixReport, serde_name, ReportSchema, Severity};
+
+use crate::action::{EXIT_ERROR, EXIT_FAILURE, EXIT_SUCCESS};
+
+use super::{tui::ProgressEvent, ReceiverContext};
+
+// --output -로 보고서를 표준 출력에 쓴다면, 진행상황과 요약은 표준 에러에 출력합니다.
+macro_rules! print_log {
+    ($to_stderr:expr) => {
+        if $to_stderr {
+            eprintln!()
+        } else {
+            println!()
+        }
+    };
+    ($to_stderr:expr, $($arg:tt)*) => {
+        if $to_stderr {
+            eprintln!($($arg)*)
+        } else {
+            println!($($arg)*)
+        }
+    };
+}
+
+// --ci 실행에서 TUI 대신 진행상황을 한 줄씩 출력합니다.
+// 모든 쌍의 작업이 끝나면 반환합니다.
+pub(super) fn run_progress_log(context: ReceiverContext, to_stderr: bool) {
+    while let Ok(event) = context.event_receiver.recv() {
+        match event {
+            ProgressEvent::StartPair(pair_name) => print_log!(to_stderr, "== Pair: {pair_name} =="),
+            ProgressEvent::Start => print_log!(to_stderr, ">> Connecting to database..."),
+            ProgressEvent::StartFetchingTableList => {
+                print_log!(to_stderr, ">> Fetching table list...")
+            }
+            ProgressEvent::FetchingTableList(_) => {}
+            ProgressEvent::StartComparingTable => print_log!(to_stderr, ">> Comparing table..."),
+            ProgressEvent::ComparingTable(_) => {}
+            ProgressEvent::SavingReportFile => print_log!(to_stderr, ">> Saving report file..."),
+            ProgressEvent::Finished => {
+                print_log!(to_stderr, ">> Finished");
+                return;
+            }
+            ProgressEvent::Error(error) => {
+                eprintln!("!! Error");
+                eprintln!("{error}");
+            }
+        }
+    }
+}
+
+// 쌍 하나의 결과를 요약해서 출력하고, 종료 코드를 반환합니다.
+pub(super) fn print_pair_summary(
+    pair_name: &str,
+    result: &anyhow::Result<ReportSchema>,
+    to_stderr: bool,
+) -> i32 {
+    print_log!(to_stderr, "== Pair: {pair_name} ==");
+
+    match result {
+        Ok(report) => print_summary(report, to_stderr),
+        Err(_) => {
+            print_log!(to_stderr, "Result: ERROR");
+            EXIT_ERROR
+        }
+    }
+}
+
+// 결과를 요약해서 출력하고, 종료 코드를 반환합니다.
+fn print_summary(report: &ReportSchema, to_stderr: bool) -> i32 {
+    let severity_name = |severity: Severity| format!("{severity:?}").to_lowercase();
+
+    let count = |severity: Severity| {
+        report
+            .findings()
+            .filter(|finding| finding.severity == severity)
+            .count()
+    };
+
+    print_log!(to_stderr);
+
+    for finding in report.findings().filter(|finding| !finding.acknowledged) {
+        print_log!(
+            to_stderr,
+            "[{}] {}",
+            severity_name(finding.severity),
+            finding.message
+        );
+    }
+
+    for warning in &report.warnings {
+        print_log!(to_stderr, "[warning] {warning}");
+    }
+
+    for fingerprint in &report.stale_baseline_entries {
+        print_log!(to_stderr, "[stale baseline] {fingerprint}");
+    }
+
+    print_log!(to_stderr);
+    print_log!(
+        to_stderr,
+        "Summary: {} findings (error: {}, warning: {}, info: {}), acknowledged: {}, suppressed: {}, stale baseline entries: {}",
+        report.findings().count(),
+        count(Severity::Error),
+        count(Severity::Warning),
+        count(Severity::Info),
+        report.findings().filter(|finding| finding.acknowledged).count(),
+        report.suppressed_count,
+        report.stale_baseline_entries.len()
+    );
+
+    if report.failure_count > 0 {
+        print_log!(
+            to_stderr,
+            "Result: FAILED ({} findings at or above {})",
+            report.failure_count,
+            severity_name(report.fail_on)
+        );
+
+        EXIT_FAILURE
+    } else {
+        print_log!(to_stderr, "Result: PASSED");
+
+        EXIT_SUCCESS
+    }
+}
+
+// 비교 그룹의 결과를 객체와 속성마다 한 줄씩 요약해서 출력하고, 종료 코드를 반환합니다.
+// 예: "users.email data_type (base: varchar(255)) - dev: ok, qa: different (varchar(100))"
+pub(super) fn print_matrix_summary(report: &MatrixReport, to_stderr: bool) -> i32 {
+    print_log!(to_stderr);
+
+    for row in &report.rows {
+        let mut line = row.object_path.clone();
+
+        if let Some(property) = row.property {
+            line.push_str(&format!(" {}", serde_name(&property)));
+        }
+
+        if let Some(base_value) = &row.base_value {
+            line.push_str(&format!(" (base: {base_value})"));
+        }
+
+        let cells: Vec<String> = row
+            .cells
+            .iter()
+            .map(|cell| match (cell.finding_kind, &cell.target_value) {
+                (None, _) => format!("{}: ok", cell.environment),
+                (Some(finding_kind), Some(target_value)) => format!(
+                    "{}: {} ({target_value})",
+                    cell.environment,
+                    serde_name(&finding_kind)
+                ),
+                (Some(finding_kind), None) => {
+                    format!("{}: {}", cell.environment, serde_name(&finding_kind))
+                }
+            })
+            .collect();
+
+        print_log!(to_stderr, "{line} - {}", cells.join(", "));
+    }
+
+    for warning in &report.warnings {
+        print_log!(to_stderr, "[warning] {warning}");
+    }
+
+    for error in &report.errors {
+        print_log!(to_stderr, "[error] {error}");
+    }
+
+    print_log!(to_stderr);
+    print_log!(
+        to_stderr,
+        "Summary: {} rows, environments: {}, failed environments: {}",
+        report.rows.len(),
+        report.environments.join(", "),
+        report.errors.len()
+    );
+
+    if !report.errors.is_empty() {
+        print_log!(to_stderr, "Result: ERROR");
+
+        EXIT_ERROR
+    } else if report.failure_count > 0 {
+        print_log!(
+            to_stderr,
+            "Result: FAILED ({} findings at or above {})",
+            report.failure_count,
+            format!("{:?}", report.fail_on).to_lowercase()
+        );
+
+        EXIT_FAILURE
+    } else {
+        print_log!(to_stderr, "Result: PASSED");
+
+        EXIT_SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use magiceye::report::{Finding, ObjectKind, ReportTable};
+
+    use super::*;
+
+    fn report(findings: Vec<Finding>, fail_on: Severity) -> ReportSchema {
+        let mut report = ReportSchema {
+            report_table_list: vec![ReportTable {
+                table_name: "users".to_string(),
+                report_list: findings,
+            }],
+            warnings: vec![],
+            suppressed_count: 0,
+            stale_baseline_entries: vec![],
+            fail_on,
+            failure_count: 0,
+        };
+        report.update_failure_count();
+
+        report
+    }
+
+    fn finding(severity: Severity) -> Finding {
+        Finding {
+            severity,
+            ..Finding::missing(ObjectKind::Column, "users.email".to_string())
+        }
+    }
+
+    #[test]
+    fn exit_code_follows_fail_on() {
+        let warning_only = || vec![finding(Severity::Warning), finding(Severity::Info)];
+
+        let result = Ok(report(warning_only(), Severity::Error));
+        assert_eq!(print_pair_summary("prod", &result, true), EXIT_SUCCESS);
+
+        let result = Ok(report(warning_only(), Severity::Warning));
+        assert_eq!(print_pair_summary("prod", &result, true), EXIT_FAILURE);
+
+        let result = Ok(report(vec![], Severity::Info));
+        assert_eq!(print_pair_summary("prod", &result, true), EXIT_SUCCESS);
+    }
+
+    #[test]
+    fn acknowledged_findings_do_not_fail() {
+        let mut acknowledged = finding(Severity::Error);
+        acknowledged.acknowledged = true;
+
+        let result = Ok(report(vec![acknowledged], Severity::Error));
+        assert_eq!(print_pair_summary("prod", &result, true), EXIT_SUCCESS);
+    }
+
+    #[test]
+    fn connection_error_is_an_error_exit() {
+        let result = Err(anyhow::anyhow!("connection refused"));
+        assert_eq!(print_pair_summary("prod", &result, true), EXIT_ERROR);
+    }
+}
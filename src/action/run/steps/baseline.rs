@@ -0,0 +1,117 @@
+use magiceye::{
+    config::Config,
+    report::{baseline::Baseline, ReportSchema},
+};
+
+use crate::{action::run::SenderContext, command::run::CommandFlags};
+
+// --baseline이 없다면 설정 파일의 baseline을 사용합니다.
+fn baseline_path<'a>(flags: &'a CommandFlags, config: &'a Config) -> Option<&'a String> {
+    flags.baseline.as_ref().or(config.baseline.as_ref())
+}
+
+// 비교를 시작하기 전에 baseline 옵션을 확인합니다. pair_count는 비교할 쌍의 수(그룹이라면 target 환경 수)입니다.
+pub fn check_baseline_flags(
+    flags: &CommandFlags,
+    config: &Config,
+    pair_count: usize,
+) -> anyhow::Result<()> {
+    if !flags.update_baseline {
+        return Ok(());
+    }
+
+    let Some(path) = baseline_path(flags, config) else {
+        return Err(anyhow::anyhow!(
+            "--update-baseline requires --baseline or baseline in the config file"
+        ));
+    };
+
+    // 쌍마다 다른 파일에 써야 하므로, 여러 쌍이라면 {pair}가 있어야 합니다.
+    // 없다면 마지막 쌍의 baseline만 남습니다.
+    if pair_count > 1 && !path.contains("{pair}") {
+        return Err(anyhow::anyhow!(
+            "the baseline path must contain {{pair}} to update baselines of more than one database pair"
+        ));
+    }
+
+    Ok(())
+}
+
+// --baseline 파일에 기록된 차이를 확인한 것으로 표시합니다.
+// --update-baseline이라면 이번 실행의 모든 차이로 baseline 파일을 새로 만듭니다.
+pub fn apply_baseline(context: &SenderContext, report: &mut ReportSchema) -> anyhow::Result<()> {
+    let Some(path) = baseline_path(&context.flags, &context.config) else {
+        if context.flags.update_baseline {
+            return Err(anyhow::anyhow!(
+                "--update-baseline requires --baseline or baseline in the config file"
+            ));
+        }
+
+        return Ok(());
+    };
+
+    // --all-pairs에서 쌍마다 다른 파일을 쓸 수 있도록 {pair}를 쌍 이름으로 바꿉니다.
+    let path = &path.replace("{pair}", &context.database_pair.name);
+
+    let baseline = if context.flags.update_baseline {
+        let baseline = Baseline::from_report(report);
+        baseline.write(path)?;
+        baseline
+    } else {
+        Baseline::read(path)?
+    };
+
+    baseline.apply(report);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flags(baseline: Option<&str>) -> CommandFlags {
+        CommandFlags {
+            baseline: baseline.map(str::to_string),
+            update_baseline: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn update_needs_baseline_path() {
+        assert!(check_baseline_flags(&flags(None), &Config::default(), 1).is_err());
+
+        let config = Config {
+            baseline: Some("baseline.json".to_string()),
+            ..Default::default()
+        };
+        assert!(check_baseline_flags(&flags(None), &config, 1).is_ok());
+    }
+
+    #[test]
+    fn update_many_pairs_needs_pair_in_path() {
+        let config = Config::default();
+
+        assert!(check_baseline_flags(&flags(Some("baseline.json")), &config, 1).is_ok());
+        assert!(check_baseline_flags(&flags(Some("baseline.json")), &config, 2).is_err());
+        assert!(check_baseline_flags(&flags(Some("baseline_{pair}.json")), &config, 2).is_ok());
+
+        // 설정 파일의 baseline도 확인합니다.
+        let config = Config {
+            baseline: Some("baseline.json".to_string()),
+            ..Default::default()
+        };
+        assert!(check_baseline_flags(&flags(None), &config, 2).is_err());
+    }
+
+    #[test]
+    fn reading_a_shared_baseline_is_allowed() {
+        let read_flags = CommandFlags {
+            update_baseline: false,
+            ..flags(Some("baseline.json"))
+        };
+
+        assert!(check_baseline_flags(&read_flags, &Config::default(), 3).is_ok());
+    }
+}
@@ -1,47 +1,181 @@
-use crate::{
-    config::{DatabasePair, DatabaseType},
-    sql::{mysql, postgres, ConnectionPool},
+use magiceye::{
+    config::{self, DatabasePair},
+    sql::{
+        self, secret, service,
+        tunnel::{self, SshTunnel, TunneledSource},
+        ConnectOptions, Database, SchemaSource,
+    },
 };
 
+use crate::action::run::tui::DatabaseSide;
+
 pub async fn connect_database(
     database_pair: &DatabasePair,
-) -> anyhow::Result<(ConnectionPool, ConnectionPool)> {
-    let base_connection_url = &database_pair.base_connection;
-    let target_connection_url = &database_pair.target_connection;
-    let database_type = &database_pair.database_type;
+) -> anyhow::Result<(Box<dyn SchemaSource>, Box<dyn SchemaSource>)> {
+    let (base_source, target_source) = tokio::join!(
+        connect_side(database_pair, DatabaseSide::Base),
+        connect_side(database_pair, DatabaseSide::Target)
+    );
 
-    let base_connection_pool = match database_type {
-        DatabaseType::Postgres => postgres::get_connection_pool(base_connection_url).await,
-        DatabaseType::Mysql => mysql::get_connection_pool(base_connection_url).await,
-    };
+    Ok((base_source?, target_source?))
+}
 
-    let target_connection_pool = match database_type {
-        DatabaseType::Postgres => postgres::get_connection_pool(target_connection_url).await,
-        DatabaseType::Mysql => mysql::get_connection_pool(target_connection_url).await,
-    };
+// base와 target 중 한쪽에만 연결합니다. 비교 그룹에서 base를 한 번만 조회할 때 사용합니다.
+pub async fn connect_side(
+    database_pair: &DatabasePair,
+    side: DatabaseSide,
+) -> anyhow::Result<Box<dyn SchemaSource>> {
+    let connection = resolve_side(database_pair, side).await?;
 
-    let base_connection_pool = match base_connection_pool {
-        Ok(pool) => pool,
+    let source = match sql::connect(
+        &database_pair.database_type,
+        &connection.tunneled_url,
+        &connection.options,
+    )
+    .await
+    {
+        Ok(source) => source,
         Err(error) => {
             return Err(anyhow::anyhow!(
-                "failed to connect to base database: {:?}",
+                "failed to connect to {} database ({}): {:?}",
+                connection.side,
+                secret::redact_url(&connection.connection_url),
                 error
             ));
         }
     };
 
-    let target_connection_pool = match target_connection_pool {
-        Ok(pool) => {
-            //println!(">> connected to target database");
-            pool
+    // 터널은 SchemaSource와 함께 drop될 때 닫힙니다.
+    match connection.ssh_tunnel {
+        Some(ssh_tunnel) => Ok(Box::new(TunneledSource::new(source, ssh_tunnel))),
+        None => Ok(source),
+    }
+}
+
+// 연결할 수 있는지만 확인하고, 데이터베이스 이름과 버전을 반환합니다. (init, config validate)
+pub async fn check_side(
+    database_pair: &DatabasePair,
+    side: DatabaseSide,
+) -> anyhow::Result<Database> {
+    let connection = resolve_side(database_pair, side).await?;
+
+    match sql::check_connection(
+        &database_pair.database_type,
+        &connection.tunneled_url,
+        &connection.options,
+    )
+    .await
+    {
+        Ok(database) => Ok(database),
+        Err(error) => Err(anyhow::anyhow!(
+            "failed to connect to {} database ({}): {error}",
+            connection.side,
+            secret::redact_url(&connection.connection_url)
+        )),
+    }
+}
+
+// 한쪽 커넥션의 설정을 모두 풀어낸 결과입니다. ssh_tunnel이 drop되면 터널이 닫힙니다.
+struct ResolvedConnection {
+    side: &'static str,
+    // 오류 메시지에 사용할, 터널을 거치기 전의 URL
+    connection_url: String,
+    // 실제로 연결할 URL
+    tunneled_url: String,
+    options: ConnectOptions,
+    ssh_tunnel: Option<SshTunnel>,
+}
+
+async fn resolve_side(
+    database_pair: &DatabasePair,
+    side: DatabaseSide,
+) -> anyhow::Result<ResolvedConnection> {
+    let database_type = &database_pair.database_type;
+
+    let (connection_url, password_command, tls, ssh_tunnel, side) = match side {
+        DatabaseSide::Base => (
+            &database_pair.base_connection,
+            &database_pair.base_password_command,
+            &database_pair.base_tls,
+            &database_pair.base_ssh_tunnel,
+            "base",
+        ),
+        DatabaseSide::Target => (
+            &database_pair.target_connection,
+            &database_pair.target_password_command,
+            &database_pair.target_tls,
+            &database_pair.target_ssh_tunnel,
+            "target",
+        ),
+    };
+
+    // 1. 커넥션 URL의 ${ENV_VAR}를 환경변수 값으로 바꿉니다.
+    let connection_url = match secret::interpolate_env(connection_url) {
+        Ok(connection_url) => connection_url,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "failed to resolve {side} connection: {error}"
+            ));
         }
+    };
+
+    // 2. pg_service.conf의 서비스나 my.cnf의 그룹을 참조한다면 커넥션 URL로 바꿉니다.
+    let connection_url = match service::resolve_connection(database_type, &connection_url) {
+        Ok(connection_url) => connection_url,
         Err(error) => {
             return Err(anyhow::anyhow!(
-                "failed to connect to target database: {:?}",
-                error
+                "failed to resolve {side} connection: {error}"
             ));
         }
     };
 
-    Ok((base_connection_pool, target_connection_pool))
+    // 3. password_command가 있다면 실행해서 비밀번호를 얻습니다.
+    let password = match password_command {
+        Some(password_command) => {
+            let password = match secret::interpolate_env(password_command) {
+                Ok(password_command) => secret::run_password_command(&password_command).await,
+                Err(error) => Err(error),
+            };
+
+            match password {
+                Ok(password) => Some(password),
+                Err(error) => {
+                    return Err(anyhow::anyhow!("failed to get {side} password: {error}"));
+                }
+            }
+        }
+        None => None,
+    };
+
+    let options = ConnectOptions {
+        pool_size: database_pair.pool_size,
+        session: database_pair.session.clone(),
+        password,
+        tls: tls.clone(),
+    };
+
+    // 4. ssh_tunnel이 있다면 터널을 열고, 터널의 로컬 포트로 연결합니다.
+    let (ssh_tunnel, tunneled_url) = match ssh_tunnel {
+        Some(ssh_tunnel) => {
+            if let Some(problem) = config::validate_tunnel_tls(side, tls, &connection_url) {
+                return Err(anyhow::anyhow!(problem));
+            }
+
+            match tunnel::open_for_url(ssh_tunnel, database_type, &connection_url).await {
+                Ok((ssh_tunnel, tunneled_url)) => (Some(ssh_tunnel), tunneled_url),
+                Err(error) => {
+                    return Err(anyhow::anyhow!("failed to open {side} ssh tunnel: {error}"));
+                }
+            }
+        }
+        None => (None, connection_url.clone()),
+    };
+
+    Ok(ResolvedConnection {
+        side,
+        connection_url,
+        tunneled_url,
+        options,
+        ssh_tunnel,
+    })
 }
@@ -1,8 +1,14 @@
 mod connection;
-pub use connection::connect_database;
+pub use connection::{check_side, connect_database, connect_side};
 
 mod fetching;
 pub use fetching::get_table_list;
 
 mod check;
 pub use check::difference_check;
+
+mod snapshot;
+pub use snapshot::check_schema_change;
+
+mod baseline;
+pub use baseline::{apply_baseline, check_baseline_flags};
@@ -0,0 +1,36 @@
+use magiceye::{config::Language, sql::SchemaSource};
+
+use crate::action::run::{tui::DatabaseSide, SenderContext};
+
+// 조회를 시작한 뒤에 스키마가 변경되었다면 경고 문장을 반환합니다.
+// 보고서는 조회를 시작한 시점의 스냅샷을 기준으로 작성됩니다.
+pub async fn check_schema_change(
+    context: &SenderContext,
+    side: DatabaseSide,
+    source: &dyn SchemaSource,
+) -> Option<String> {
+    let side = match side {
+        DatabaseSide::Base => "base",
+        DatabaseSide::Target => "target",
+    };
+
+    match source.schema_changed().await {
+        Ok(false) => None,
+        Ok(true) => Some(match context.config.current_language {
+            Language::Korean => format!(
+                "{side} 데이터베이스의 스키마가 조회 중에 변경되었습니다. 보고서는 조회를 시작한 시점을 기준으로 작성되었습니다."
+            ),
+            Language::English => format!(
+                "The schema of the {side} database changed during introspection. The report reflects the schema at the start of the run."
+            ),
+        }),
+        Err(error) => Some(match context.config.current_language {
+            Language::Korean => format!(
+                "{side} 데이터베이스의 스키마 변경 여부를 확인하지 못했습니다: {error}"
+            ),
+            Language::English => format!(
+                "Failed to verify whether the schema of the {side} database changed during introspection: {error}"
+            ),
+        }),
+    }
+}
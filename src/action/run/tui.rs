@@ -2,7 +2,14 @@ use crate::action::enter_tui;
 
 use super::ReceiverContext;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DatabaseSide {
+    Base,
+    Target,
+}
+
 pub(super) struct FetchingTableList {
+    pub side: DatabaseSide,
     pub total_count: Option<usize>,
     pub current_count: usize,
 }
@@ -35,9 +42,10 @@ impl ComparingTable {
 }
 
 pub(super) enum ProgressEvent {
+    // 여러 쌍을 비교할 때, 새 쌍의 비교를 시작함
+    StartPair(String),
     Start,
-    StartFetchingBaseTableList,
-    StartFetchingTargetTableList,
+    StartFetchingTableList,
     FetchingTableList(FetchingTableList),
     StartComparingTable,
     ComparingTable(ComparingTable),
@@ -49,11 +57,10 @@ pub(super) enum ProgressEvent {
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Step {
     Start = 0,
-    FetchingBaseTableList = 1,
-    FetchingTargetTableList = 2,
-    ComparingTable = 3,
-    SavingReportFile = 4,
-    Finished = 5,
+    FetchingTableList = 1,
+    ComparingTable = 2,
+    SavingReportFile = 3,
+    Finished = 4,
 }
 
 const STEP_COUNT: usize = Step::SavingReportFile as usize + 1;
@@ -84,7 +91,14 @@ pub(super) fn run_progress_view(context: ReceiverContext) -> anyhow::Result<()>
 
     let mut error_text = None;
 
-    let mut fetching_table_list_status = FetchingTableList {
+    let mut base_fetching_status = FetchingTableList {
+        side: DatabaseSide::Base,
+        total_count: None,
+        current_count: 0,
+    };
+
+    let mut target_fetching_status = FetchingTableList {
+        side: DatabaseSide::Target,
         total_count: None,
         current_count: 0,
     };
@@ -101,23 +115,36 @@ pub(super) fn run_progress_view(context: ReceiverContext) -> anyhow::Result<()>
         render_text.clear();
 
         // handle new event
-        if let Ok(event) = event_receiver.try_recv() {
+        // 테이블 단위 진행 이벤트가 많으므로, 쌓인 이벤트를 한 번에 모두 처리합니다.
+        while let Ok(event) = event_receiver.try_recv() {
             match event {
+                ProgressEvent::StartPair(pair_name) => {
+                    // 이전 쌍의 결과를 쌓아두고, 새 쌍의 진행상황을 처음부터 표시합니다.
+                    if current_step == Step::SavingReportFile {
+                        stacked_text.push_str(">> Saving report file - DONE ☑ \n");
+                    }
+
+                    if let Some(error) = error_text.take() {
+                        stacked_text.push_str(format!("!! Error\n{error}\n").as_str());
+                    }
+
+                    current_step = Step::Start;
+                    stacked_text.push_str(format!("== Pair: {pair_name} ==\n").as_str());
+                }
                 ProgressEvent::Start => {}
-                ProgressEvent::StartFetchingBaseTableList => {
-                    current_step = Step::FetchingBaseTableList;
+                ProgressEvent::StartFetchingTableList => {
+                    current_step = Step::FetchingTableList;
                     stacked_text.push_str(">> Database Connected - DONE ☑ \n");
                 }
-                ProgressEvent::StartFetchingTargetTableList => {
-                    current_step = Step::FetchingTargetTableList;
-                    stacked_text.push_str(">> Fetching base table list - DONE ☑ \n");
-                }
                 ProgressEvent::FetchingTableList(fetching_table_list) => {
-                    fetching_table_list_status = fetching_table_list;
+                    match fetching_table_list.side {
+                        DatabaseSide::Base => base_fetching_status = fetching_table_list,
+                        DatabaseSide::Target => target_fetching_status = fetching_table_list,
+                    }
                 }
                 ProgressEvent::StartComparingTable => {
                     current_step = Step::ComparingTable;
-                    stacked_text.push_str(">> Fetching target table list - DONE ☑ \n");
+                    stacked_text.push_str(">> Fetching table list - DONE ☑ \n");
                 }
                 ProgressEvent::ComparingTable(comparing_table) => {
                     comparison_table_status = comparing_table;
@@ -127,8 +154,11 @@ pub(super) fn run_progress_view(context: ReceiverContext) -> anyhow::Result<()>
                     stacked_text.push_str(">> Comparing table - DONE ☑ \n");
                 }
                 ProgressEvent::Finished => {
+                    if current_step == Step::SavingReportFile {
+                        stacked_text.push_str(">> Saving report file - DONE ☑ \n");
+                    }
+
                     current_step = Step::Finished;
-                    stacked_text.push_str(">> Saving report file - DONE ☑ \n");
                 }
                 ProgressEvent::Error(error) => {
                     error_text = Some(error);
@@ -155,20 +185,12 @@ pub(super) fn run_progress_view(context: ReceiverContext) -> anyhow::Result<()>
             Step::Start => {
                 render_text.push_str(">>> Database Connecting...\n");
             }
-            Step::FetchingBaseTableList => {
-                render_text.push_str(
-                    format!(
-                        ">>> Fetching base table list... - [{}]\n",
-                        fetching_table_list_status.percentage()
-                    )
-                    .as_str(),
-                );
-            }
-            Step::FetchingTargetTableList => {
+            Step::FetchingTableList => {
                 render_text.push_str(
                     format!(
-                        ">>> Fetching target table list... - [{}]\n",
-                        fetching_table_list_status.percentage()
+                        ">>> Fetching table list... - [base {}, target {}]\n",
+                        base_fetching_status.percentage(),
+                        target_fetching_status.percentage()
                     )
                     .as_str(),
                 );
@@ -228,6 +250,7 @@ pub(super) fn run_progress_view(context: ReceiverContext) -> anyhow::Result<()>
                         KeyCode::Char('q') => {
                             break;
                         }
+                        #[allow(clippy::collapsible_match)]
                         KeyCode::Enter => {
                             if current_step == Step::Finished || error_text.is_some() {
                                 break;
@@ -0,0 +1,671 @@
+use std::collections::HashMap;
+
+mod ignore;
+mod rename;
+
+use crate::{
+    config::{CheckType, IgnoreRule, Language, Severity},
+    report::{Finding, FindingKind, ObjectKind, ReportSchema, ReportTable},
+    sql::{Column, ForeignKey, Index, Property, Table},
+};
+
+// 비교 옵션
+#[derive(Debug, Clone, Default)]
+pub struct CheckOptions {
+    pub language: Language,
+    // true라면 target에만 있는 객체도 Extra로 보고합니다.
+    pub bidirectional: bool,
+    // 이 규칙에 해당하는 항목은 보고하지 않고, 개수만 기록합니다.
+    pub ignore_rules: Vec<IgnoreRule>,
+    // CheckType별 심각도. 없는 항목은 CheckType::default_severity를 사용합니다.
+    pub severity_policy: HashMap<CheckType, Severity>,
+    // 이 심각도 이상인 항목을 실패로 셉니다.
+    pub fail_on: Severity,
+}
+
+// base 테이블을 기준점으로 삼아서, target 테이블과 비교합니다.
+// on_progress는 (비교가 끝난 테이블 수, 전체 테이블 수)로 호출됩니다.
+pub fn difference_check(
+    options: &CheckOptions,
+    base_table_map: HashMap<String, Table>,
+    target_table_map: HashMap<String, Table>,
+    mut on_progress: impl FnMut(usize, usize),
+) -> ReportSchema {
+    let mut report = ReportSchema {
+        report_table_list: vec![],
+        warnings: vec![],
+        suppressed_count: 0,
+        stale_baseline_entries: vec![],
+        fail_on: options.fail_on,
+        failure_count: 0,
+    };
+
+    let table_count = base_table_map.len();
+
+    // 이름으로 짝을 찾지 못한 테이블 중, 이름만 바뀐 것으로 보이는 짝을 찾습니다.
+    // bidirectional 모드가 아니어도 target에만 있는 테이블을 짝의 후보로 사용합니다.
+    let unmatched_base_tables: Vec<&Table> = base_table_map
+        .values()
+        .filter(|table| !target_table_map.contains_key(&table.name))
+        .collect();
+    let unmatched_target_tables: Vec<&Table> = target_table_map
+        .values()
+        .filter(|table| !base_table_map.contains_key(&table.name))
+        .collect();
+
+    let renamed_tables = rename::pair_tables(&unmatched_base_tables, &unmatched_target_tables);
+
+    on_progress(0, table_count);
+
+    for (i, (base_table_name, base_table)) in base_table_map.iter().enumerate() {
+        on_progress(i + 1, table_count);
+
+        let mut report_table = ReportTable {
+            table_name: base_table_name.clone(),
+            report_list: vec![],
+        };
+
+        if let Some(target_table) = target_table_map.get(base_table_name) {
+            compare_table(options, &mut report_table, base_table, target_table);
+        } else if let Some(renamed) = rename::find_rename(&renamed_tables, base_table) {
+            report_table.report_list.push(Finding::possibly_renamed(
+                ObjectKind::Table,
+                base_table_name.clone(),
+                base_table_name.clone(),
+                renamed.target.name.clone(),
+                renamed.confidence,
+            ));
+
+            compare_table(options, &mut report_table, base_table, renamed.target);
+        } else {
+            report_table
+                .report_list
+                .push(Finding::missing(ObjectKind::Table, base_table_name.clone()));
+        }
+
+        if !report_table.report_list.is_empty() {
+            report.report_table_list.push(report_table);
+        }
+    }
+
+    // target에만 있는 테이블
+    if options.bidirectional {
+        for target_table in unmatched_target_tables {
+            if rename::is_renamed_target(&renamed_tables, target_table) {
+                continue;
+            }
+
+            report.report_table_list.push(ReportTable {
+                table_name: target_table.name.clone(),
+                report_list: vec![Finding::extra(ObjectKind::Table, target_table.name.clone())],
+            });
+        }
+    }
+
+    ignore::apply_ignore_rules(&mut report, &options.ignore_rules);
+
+    for report_table in report.report_table_list.iter_mut() {
+        for finding in report_table.report_list.iter_mut() {
+            // 이름만 다른 항목과 이름이 바뀐 것으로 추정한 항목은 정해진 낮은 심각도로 보고합니다.
+            // 추정만으로 fail_on에 걸려서 실패하지 않도록, 심각도 정책을 적용하지 않습니다.
+            if !matches!(
+                finding.finding_kind,
+                FindingKind::Renamed | FindingKind::PossiblyRenamed
+            ) {
+                let check_type = finding.check_type();
+
+                finding.severity = options
+                    .severity_policy
+                    .get(&check_type)
+                    .copied()
+                    .unwrap_or_else(|| check_type.default_severity());
+            }
+
+            finding.message = finding.render(&options.language);
+        }
+    }
+
+    report.update_failure_count();
+
+    on_progress(table_count, table_count);
+
+    report
+}
+
+// 짝을 이룬 두 테이블의 컬럼, 인덱스, 외래키를 비교합니다.
+fn compare_table(
+    options: &CheckOptions,
+    report_table: &mut ReportTable,
+    base_table: &Table,
+    target_table: &Table,
+) {
+    // 이름으로 짝을 찾지 못한 컬럼 중, 이름만 바뀐 것으로 보이는 짝을 찾습니다.
+    let renamed_columns = rename::pair_columns(base_table, target_table);
+
+    if is_checked(&base_table.unchecked, Property::Comment)
+        && base_table.comment != target_table.comment
+    {
+        report_table.report_list.push(Finding::different(
+            ObjectKind::Table,
+            base_table.name.clone(),
+            Property::Comment,
+            base_table.comment.clone(),
+            target_table.comment.clone(),
+        ));
+    }
+
+    for column in &base_table.columns {
+        let target_column = target_table.columns.iter().find(|c| c.name == column.name);
+
+        if target_column.is_none() {
+            if let Some(renamed) = rename::find_rename(&renamed_columns, column) {
+                report_table.report_list.push(Finding::possibly_renamed(
+                    ObjectKind::Column,
+                    format!("{}.{}", base_table.name, column.name),
+                    column.name.clone(),
+                    renamed.target.name.clone(),
+                    renamed.confidence,
+                ));
+
+                compare_column(report_table, base_table, column, Some(renamed.target));
+                continue;
+            }
+        }
+
+        compare_column(report_table, base_table, column, target_column);
+    }
+
+    for index in &base_table.indexes {
+        let target_index = target_table.find_index(index, base_table);
+
+        compare_index(report_table, base_table, index, target_index);
+    }
+
+    for foreign_key in base_table.foreign_keys() {
+        let target_foreign_key = target_table.find_foreign_key(foreign_key, base_table);
+
+        compare_foreign_key(report_table, base_table, foreign_key, target_foreign_key);
+    }
+
+    if options.bidirectional {
+        find_extra_objects(report_table, base_table, target_table, &renamed_columns);
+    }
+}
+
+// target 테이블에만 있는 컬럼, 인덱스, 외래키를 찾습니다.
+fn find_extra_objects(
+    report_table: &mut ReportTable,
+    base_table: &Table,
+    target_table: &Table,
+    renamed_columns: &[rename::Rename<'_, Column>],
+) {
+    let table_name = &target_table.name;
+
+    for target_column in &target_table.columns {
+        if !base_table
+            .columns
+            .iter()
+            .any(|c| c.name == target_column.name)
+            && !rename::is_renamed_target(renamed_columns, target_column)
+        {
+            report_table.report_list.push(Finding::extra(
+                ObjectKind::Column,
+                format!("{table_name}.{}", target_column.name),
+            ));
+        }
+    }
+
+    for target_index in &target_table.indexes {
+        if base_table.find_index(target_index, target_table).is_none() {
+            report_table.report_list.push(Finding::extra(
+                ObjectKind::Index,
+                format!("{table_name}.{}", target_index.display_name()),
+            ));
+        }
+    }
+
+    let mut reported_foreign_keys = vec![];
+
+    for target_foreign_key in target_table.foreign_keys() {
+        if base_table
+            .find_foreign_key(target_foreign_key, target_table)
+            .is_none()
+            && !reported_foreign_keys.contains(&&target_foreign_key.name)
+        {
+            reported_foreign_keys.push(&target_foreign_key.name);
+
+            report_table.report_list.push(Finding::extra(
+                ObjectKind::ForeignKey,
+                format!("{table_name}.{}", target_foreign_key.display_name()),
+            ));
+        }
+    }
+}
+
+// 명세 파일처럼 일부 속성만 정의된 경우, 정의되지 않은 속성은 비교하지 않습니다.
+fn is_checked(unchecked: &[Property], property: Property) -> bool {
+    !unchecked.contains(&property)
+}
+
+// 이름 없이 정의만으로 찾은 경우(명세 파일)는 이름이 다르다고 보지 않습니다.
+fn is_renamed(base_name: &str, target_name: &str) -> bool {
+    !base_name.is_empty() && !target_name.is_empty() && base_name != target_name
+}
+
+fn compare_column(
+    report_table: &mut ReportTable,
+    base_table: &Table,
+    base_column: &Column,
+    target_column: Option<&Column>,
+) {
+    let object_path = format!("{}.{}", base_table.name, base_column.name);
+
+    let Some(target_column) = target_column else {
+        report_table
+            .report_list
+            .push(Finding::missing(ObjectKind::Column, object_path));
+        return;
+    };
+
+    let nullable = |column: &Column| if column.nullable { "NULL" } else { "NOT NULL" };
+    let auto_increment = |column: &Column| {
+        if column.is_auto_increment {
+            "AUTO_INCREMENT"
+        } else {
+            "NOT AUTO_INCREMENT"
+        }
+    };
+
+    let differences = [
+        (
+            Property::DataType,
+            base_column.data_type.clone(),
+            target_column.data_type.clone(),
+        ),
+        (
+            Property::Comment,
+            base_column.comment.clone(),
+            target_column.comment.clone(),
+        ),
+        (
+            Property::Nullable,
+            nullable(base_column).to_string(),
+            nullable(target_column).to_string(),
+        ),
+        (
+            Property::Default,
+            base_column.default.clone(),
+            target_column.default.clone(),
+        ),
+        (
+            Property::AutoIncrement,
+            auto_increment(base_column).to_string(),
+            auto_increment(target_column).to_string(),
+        ),
+    ];
+
+    for (property, base_value, target_value) in differences {
+        if is_checked(&base_column.unchecked, property) && base_value != target_value {
+            report_table.report_list.push(Finding::different(
+                ObjectKind::Column,
+                object_path.clone(),
+                property,
+                base_value,
+                target_value,
+            ));
+        }
+    }
+}
+
+fn compare_index(
+    report_table: &mut ReportTable,
+    base_table: &Table,
+    base_index: &Index,
+    target_index: Option<&Index>,
+) {
+    let object_path = format!("{}.{}", base_table.name, base_index.display_name());
+
+    let Some(target_index) = target_index else {
+        report_table
+            .report_list
+            .push(Finding::missing(ObjectKind::Index, object_path));
+        return;
+    };
+
+    if is_renamed(&base_index.name, &target_index.name) {
+        report_table.report_list.push(Finding::renamed(
+            ObjectKind::Index,
+            object_path.clone(),
+            base_index.name.clone(),
+            target_index.name.clone(),
+        ));
+    }
+
+    let uniqueness = |index: &Index| {
+        if index.is_unique {
+            "UNIQUE"
+        } else {
+            "NOT UNIQUE"
+        }
+    };
+
+    let differences = [
+        (
+            Property::Columns,
+            base_index.columns.join(", "),
+            target_index.columns.join(", "),
+        ),
+        (
+            Property::Predicate,
+            base_index.predicate.clone(),
+            target_index.predicate.clone(),
+        ),
+        (
+            Property::Unique,
+            uniqueness(base_index).to_string(),
+            uniqueness(target_index).to_string(),
+        ),
+    ];
+
+    for (property, base_value, target_value) in differences {
+        if is_checked(&base_index.unchecked, property) && base_value != target_value {
+            report_table.report_list.push(Finding::different(
+                ObjectKind::Index,
+                object_path.clone(),
+                property,
+                base_value,
+                target_value,
+            ));
+        }
+    }
+}
+
+fn compare_foreign_key(
+    report_table: &mut ReportTable,
+    base_table: &Table,
+    base_foreign_key: &ForeignKey,
+    target_foreign_key: Option<&ForeignKey>,
+) {
+    let object_path = format!("{}.{}", base_table.name, base_foreign_key.display_name());
+
+    let Some(target_foreign_key) = target_foreign_key else {
+        report_table
+            .report_list
+            .push(Finding::missing(ObjectKind::ForeignKey, object_path));
+        return;
+    };
+
+    // 여러 컬럼으로 구성된 외래키는 컬럼마다 항목이 있으므로, 한 번만 보고합니다.
+    if is_renamed(&base_foreign_key.name, &target_foreign_key.name)
+        && !report_table.report_list.iter().any(|finding| {
+            finding.finding_kind == FindingKind::Renamed && finding.object_path == object_path
+        })
+    {
+        report_table.report_list.push(Finding::renamed(
+            ObjectKind::ForeignKey,
+            object_path.clone(),
+            base_foreign_key.name.clone(),
+            target_foreign_key.name.clone(),
+        ));
+    }
+
+    // 외래키가 참조하는 테이블이 다르면 보고합니다.
+    if base_foreign_key.foreign_column != target_foreign_key.foreign_column {
+        let references = |foreign_key: &ForeignKey| {
+            format!(
+                "{}.{}",
+                foreign_key.foreign_column.table_name, foreign_key.foreign_column.column_name
+            )
+        };
+
+        report_table.report_list.push(Finding::different(
+            ObjectKind::ForeignKey,
+            object_path,
+            Property::References,
+            references(base_foreign_key),
+            references(target_foreign_key),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(name: &str, columns: &[&str]) -> Table {
+        Table {
+            name: name.to_string(),
+            columns: columns
+                .iter()
+                .map(|name| Column {
+                    name: name.to_string(),
+                    data_type: "text".to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn finding_kinds(options: &CheckOptions) -> Vec<(String, FindingKind)> {
+        let base_table_map = HashMap::from([(
+            "users".to_string(),
+            table("users", &["id", "email", "name", "created_at"]),
+        )]);
+        let target_table_map = HashMap::from([(
+            "accounts".to_string(),
+            table("accounts", &["id", "email", "name", "created_at"]),
+        )]);
+
+        let report = difference_check(options, base_table_map, target_table_map, |_, _| {});
+
+        let mut finding_kinds: Vec<(String, FindingKind)> = report
+            .findings()
+            .map(|finding| (finding.object_path.clone(), finding.finding_kind))
+            .collect();
+        finding_kinds.sort_by(|a, b| a.0.cmp(&b.0));
+
+        finding_kinds
+    }
+
+    #[test]
+    fn table_comment_is_compared() {
+        let mut base_table = table("users", &["id"]);
+        base_table.comment = "login accounts".to_string();
+        let target_table = table("users", &["id"]);
+
+        let report = difference_check(
+            &CheckOptions::default(),
+            HashMap::from([("users".to_string(), base_table.clone())]),
+            HashMap::from([("users".to_string(), target_table.clone())]),
+            |_, _| {},
+        );
+        let findings: Vec<&Finding> = report.findings().collect();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].check_type(), CheckType::CommentOfTable);
+        assert_eq!(findings[0].base_value.as_deref(), Some("login accounts"));
+        assert_eq!(findings[0].target_value.as_deref(), Some(""));
+
+        // 명세 파일에 정의되지 않은 comment는 비교하지 않습니다.
+        base_table.unchecked.push(Property::Comment);
+
+        let report = difference_check(
+            &CheckOptions::default(),
+            HashMap::from([("users".to_string(), base_table)]),
+            HashMap::from([("users".to_string(), target_table)]),
+            |_, _| {},
+        );
+
+        assert_eq!(report.findings().count(), 0);
+    }
+
+    #[test]
+    fn renames_are_detected_in_both_modes() {
+        assert_eq!(
+            finding_kinds(&CheckOptions::default()),
+            [("users".to_string(), FindingKind::PossiblyRenamed)]
+        );
+
+        let options = CheckOptions {
+            bidirectional: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            finding_kinds(&options),
+            [("users".to_string(), FindingKind::PossiblyRenamed)]
+        );
+    }
+
+    #[test]
+    fn severity_policy_does_not_apply_to_possibly_renamed() {
+        let options = CheckOptions {
+            severity_policy: HashMap::from([(CheckType::Table, Severity::Error)]),
+            fail_on: Severity::Error,
+            ..Default::default()
+        };
+
+        let report = difference_check(
+            &options,
+            HashMap::from([(
+                "users".to_string(),
+                table("users", &["id", "email", "name", "created_at"]),
+            )]),
+            HashMap::from([(
+                "accounts".to_string(),
+                table("accounts", &["id", "email", "name", "created_at"]),
+            )]),
+            |_, _| {},
+        );
+        let findings: Vec<&Finding> = report.findings().collect();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].finding_kind, FindingKind::PossiblyRenamed);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert_eq!(report.failure_count, 0);
+    }
+
+    #[test]
+    fn renamed_columns_are_detected_without_bidirectional() {
+        let base_table = table("users", &["id", "email", "created_at"]);
+        let target_table = table("users", &["id", "email_address", "created_at"]);
+
+        let report = difference_check(
+            &CheckOptions::default(),
+            HashMap::from([("users".to_string(), base_table)]),
+            HashMap::from([("users".to_string(), target_table)]),
+            |_, _| {},
+        );
+        let findings: Vec<&Finding> = report.findings().collect();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].finding_kind, FindingKind::PossiblyRenamed);
+        assert_eq!(findings[0].object_path, "users.email");
+        assert_eq!(findings[0].target_value.as_deref(), Some("email_address"));
+    }
+
+    #[test]
+    fn renamed_index_and_foreign_key_are_reported_as_renamed() {
+        let with_keys = |index_name: &str, foreign_key_name: &str| {
+            let mut table = table("posts", &["id", "user_id"]);
+            table.indexes.push(Index {
+                name: index_name.to_string(),
+                columns: vec!["user_id".to_string()],
+                ..Default::default()
+            });
+            table.constraints.push(
+                ForeignKey {
+                    name: foreign_key_name.to_string(),
+                    column: vec!["user_id".to_string()],
+                    foreign_column: crate::sql::SelectColumn {
+                        table_name: "users".to_string(),
+                        column_name: "id".to_string(),
+                    },
+                }
+                .into(),
+            );
+            table
+        };
+
+        let report = difference_check(
+            &CheckOptions {
+                fail_on: Severity::Info,
+                ..Default::default()
+            },
+            HashMap::from([("posts".to_string(), with_keys("idx_1234", "posts_ibfk_1"))]),
+            HashMap::from([("posts".to_string(), with_keys("idx_9876", "posts_ibfk_2"))]),
+            |_, _| {},
+        );
+        let mut findings: Vec<&Finding> = report.findings().collect();
+        findings.sort_by(|a, b| a.object_path.cmp(&b.object_path));
+
+        assert_eq!(findings.len(), 2);
+        assert!(findings
+            .iter()
+            .all(|finding| finding.finding_kind == FindingKind::Renamed
+                && finding.severity == Severity::Info));
+        assert_eq!(findings[0].object_path, "posts.idx_1234");
+        assert_eq!(findings[0].target_value.as_deref(), Some("idx_9876"));
+        assert_eq!(findings[1].object_path, "posts.posts_ibfk_1");
+        assert_eq!(findings[1].target_value.as_deref(), Some("posts_ibfk_2"));
+    }
+
+    #[test]
+    fn severity_policy_overrides_default_severity() {
+        let mut base_table = table("users", &["id", "email"]);
+        base_table.comment = "login accounts".to_string();
+        base_table.columns[1].data_type = "varchar(255)".to_string();
+        let target_table = table("users", &["id", "email"]);
+
+        let severities = |options: &CheckOptions| {
+            let report = difference_check(
+                options,
+                HashMap::from([("users".to_string(), base_table.clone())]),
+                HashMap::from([("users".to_string(), target_table.clone())]),
+                |_, _| {},
+            );
+            let mut severities: Vec<(CheckType, Severity)> = report
+                .findings()
+                .map(|finding| (finding.check_type(), finding.severity))
+                .collect();
+            severities.sort_by_key(|(_, severity)| *severity);
+
+            (severities, report.failure_count)
+        };
+
+        assert_eq!(
+            severities(&CheckOptions::default()),
+            (
+                vec![
+                    (CheckType::CommentOfTable, Severity::Info),
+                    (CheckType::TypeOfColumn, Severity::Error)
+                ],
+                1
+            )
+        );
+
+        let options = CheckOptions {
+            severity_policy: HashMap::from([
+                (CheckType::CommentOfTable, Severity::Error),
+                (CheckType::TypeOfColumn, Severity::Warning),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            severities(&options),
+            (
+                vec![
+                    (CheckType::TypeOfColumn, Severity::Warning),
+                    (CheckType::CommentOfTable, Severity::Error)
+                ],
+                1
+            )
+        );
+
+        let options = CheckOptions {
+            fail_on: Severity::Info,
+            ..Default::default()
+        };
+        assert_eq!(severities(&options).1, 2);
+    }
+}
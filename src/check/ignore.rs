@@ -0,0 +1,164 @@
+use crate::{
+    config::IgnoreRule,
+    report::{Finding, ReportSchema},
+};
+
+// 무시 규칙에 해당하는 항목을 보고서에서 제외하고, 제외한 수를 기록합니다.
+pub(super) fn apply_ignore_rules(report: &mut ReportSchema, ignore_rules: &[IgnoreRule]) {
+    if ignore_rules.is_empty() {
+        return;
+    }
+
+    for report_table in report.report_table_list.iter_mut() {
+        let count = report_table.report_list.len();
+
+        report_table
+            .report_list
+            .retain(|finding| !is_ignored(finding, ignore_rules));
+
+        report.suppressed_count += count - report_table.report_list.len();
+    }
+
+    report
+        .report_table_list
+        .retain(|report_table| !report_table.report_list.is_empty());
+}
+
+fn is_ignored(finding: &Finding, ignore_rules: &[IgnoreRule]) -> bool {
+    let check_type = finding.check_type();
+
+    ignore_rules.iter().any(|rule| {
+        rule.check_type == check_type
+            && rule
+                .pattern
+                .as_deref()
+                .is_none_or(|pattern| glob_match(pattern, &finding.object_path))
+    })
+}
+
+// *는 0개 이상의 아무 문자, ?는 아무 문자 하나와 일치합니다.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // 마지막으로 만난 *의 위치와, 그 *가 일치시킨 text의 끝 위치
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::CheckType,
+        report::{ObjectKind, ReportTable},
+        sql::Property,
+    };
+
+    #[test]
+    fn glob_match_table_wildcard() {
+        assert!(glob_match("users.*", "users.email"));
+        assert!(glob_match("users.*", "users."));
+        // *는 점(.)도 일치시킵니다.
+        assert!(glob_match("users.*", "users.posts_user_id_fkey.x"));
+        // 테이블 자체는 table.*에 해당하지 않습니다.
+        assert!(!glob_match("users.*", "users"));
+        assert!(!glob_match("users.*", "users_backup.email"));
+        assert!(!glob_match("users.*", "app_users.email"));
+    }
+
+    #[test]
+    fn glob_match_column_wildcard() {
+        assert!(glob_match("*.created_at", "users.created_at"));
+        assert!(glob_match("*.created_at", "audit_log.created_at"));
+        assert!(!glob_match("*.created_at", "users.created_at_utc"));
+        assert!(!glob_match("*.created_at", "users.updated_at"));
+        assert!(!glob_match("*.created_at", "created_at"));
+    }
+
+    #[test]
+    fn glob_match_literal_path() {
+        assert!(glob_match("users.email", "users.email"));
+        assert!(!glob_match("users.email", "users.email2"));
+        assert!(!glob_match("users.email", "users.emai"));
+        assert!(!glob_match("users.email", "Users.email"));
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "users"));
+    }
+
+    #[test]
+    fn glob_match_question_mark_and_backtracking() {
+        assert!(glob_match("legacy_?", "legacy_1"));
+        assert!(!glob_match("legacy_?", "legacy_10"));
+        assert!(glob_match("*_tmp_*", "orders_tmp_2024"));
+        // 첫 번째 _id에서 멈추지 않고, 마지막 _id까지 다시 찾습니다.
+        assert!(glob_match("*_id", "user_id_old_id"));
+        assert!(glob_match("**", ""));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+        assert!(!glob_match("a*b*c", "aXbYbZ"));
+    }
+
+    #[test]
+    fn ignore_rules_match_check_type_and_pattern() {
+        let mut report = ReportSchema {
+            report_table_list: vec![ReportTable {
+                table_name: "users".to_string(),
+                report_list: vec![
+                    Finding::missing(ObjectKind::Column, "users.created_at".to_string()),
+                    Finding::different(
+                        ObjectKind::Column,
+                        "users.created_at".to_string(),
+                        Property::Comment,
+                        "a".to_string(),
+                        "b".to_string(),
+                    ),
+                    Finding::missing(ObjectKind::Column, "users.email".to_string()),
+                ],
+            }],
+            warnings: vec![],
+            suppressed_count: 0,
+            stale_baseline_entries: vec![],
+            fail_on: Default::default(),
+            failure_count: 0,
+        };
+
+        let ignore_rules = [
+            IgnoreRule {
+                check_type: CheckType::ColumnOfTable,
+                pattern: Some("*.created_at".to_string()),
+            },
+            // 패턴이 없다면 해당 CheckType을 모두 무시합니다.
+            IgnoreRule {
+                check_type: CheckType::CommentOfColumn,
+                pattern: None,
+            },
+        ];
+
+        apply_ignore_rules(&mut report, &ignore_rules);
+
+        let paths: Vec<&str> = report
+            .findings()
+            .map(|finding| finding.object_path.as_str())
+            .collect();
+        assert_eq!(paths, ["users.email"]);
+        assert_eq!(report.suppressed_count, 2);
+    }
+}
@@ -0,0 +1,463 @@
+use crate::sql::{Column, Property, Table};
+
+// 이 값 이상의 점수를 받은 짝만 이름이 바뀐 것으로 봅니다.
+const CONFIDENCE_THRESHOLD: f64 = 0.65;
+
+// 이름이 바뀐 것으로 보이는 base와 target 객체의 짝
+pub(super) struct Rename<'a, T> {
+    pub base: &'a T,
+    pub target: &'a T,
+    pub confidence: f64,
+}
+
+// base 객체와 짝을 이룬 target 객체를 찾습니다.
+pub(super) fn find_rename<'a, 'b, T>(
+    renames: &'b [Rename<'a, T>],
+    base: &T,
+) -> Option<&'b Rename<'a, T>> {
+    renames
+        .iter()
+        .find(|rename| std::ptr::eq(rename.base, base))
+}
+
+// target 객체가 이미 짝을 이루었는지 확인합니다.
+pub(super) fn is_renamed_target<T>(renames: &[Rename<'_, T>], target: &T) -> bool {
+    renames
+        .iter()
+        .any(|rename| std::ptr::eq(rename.target, target))
+}
+
+// 점수가 높은 짝부터 차례로 고르고, 이미 고른 객체는 다시 고르지 않습니다.
+fn pair<'a, T>(
+    base_list: &[&'a T],
+    target_list: &[&'a T],
+    score: impl Fn(&T, &T) -> f64,
+) -> Vec<Rename<'a, T>> {
+    let mut candidates = vec![];
+
+    for base in base_list {
+        for target in target_list {
+            let confidence = score(base, target);
+
+            if confidence >= CONFIDENCE_THRESHOLD {
+                candidates.push(Rename {
+                    base: *base,
+                    target: *target,
+                    confidence,
+                });
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+    let mut renames: Vec<Rename<'a, T>> = vec![];
+
+    for candidate in candidates {
+        if find_rename(&renames, candidate.base).is_none()
+            && !is_renamed_target(&renames, candidate.target)
+        {
+            renames.push(candidate);
+        }
+    }
+
+    renames
+}
+
+fn name_similarity(base_name: &str, target_name: &str) -> f64 {
+    strsim::jaro_winkler(&base_name.to_lowercase(), &target_name.to_lowercase())
+}
+
+// 이름으로 짝을 찾지 못한 테이블끼리 비교합니다.
+// 컬럼 구성(이름, 타입)이 얼마나 겹치는지와 테이블 이름이 얼마나 비슷한지로 점수를 매깁니다.
+pub(super) fn pair_tables<'a>(
+    base_tables: &[&'a Table],
+    target_tables: &[&'a Table],
+) -> Vec<Rename<'a, Table>> {
+    pair(base_tables, target_tables, table_score)
+}
+
+fn table_score(base_table: &Table, target_table: &Table) -> f64 {
+    let signature = |column: &Column| format!("{} {}", column.name, column.data_type);
+
+    let base_columns: Vec<String> = base_table.columns.iter().map(signature).collect();
+    let target_columns: Vec<String> = target_table.columns.iter().map(signature).collect();
+
+    let shared_count = base_columns
+        .iter()
+        .filter(|column| target_columns.contains(column))
+        .count();
+    let union_count = base_columns.len() + target_columns.len() - shared_count;
+
+    // 컬럼이 적은 테이블은 우연히 겹치기 쉬우므로, 컬럼 점수를 낮춥니다.
+    let column_score = if union_count == 0 {
+        0.0
+    } else {
+        (shared_count as f64 / union_count as f64) * (union_count as f64 / 4.0).min(1.0)
+    };
+
+    0.6 * column_score + 0.4 * name_similarity(&base_table.name, &target_table.name)
+}
+
+// 양쪽에 모두 있는 테이블에서, 이름으로 짝을 찾지 못한 컬럼끼리 비교합니다.
+// 타입이 같아야 하며, 이름, 위치, NULLABLE과 DEFAULT, 인덱스와 외래키 구성으로 점수를 매깁니다.
+pub(super) fn pair_columns<'a>(
+    base_table: &'a Table,
+    target_table: &'a Table,
+) -> Vec<Rename<'a, Column>> {
+    let base_columns: Vec<&Column> = base_table
+        .columns
+        .iter()
+        .filter(|column| !target_table.columns.iter().any(|c| c.name == column.name))
+        .collect();
+
+    let target_columns: Vec<&Column> = target_table
+        .columns
+        .iter()
+        .filter(|column| !base_table.columns.iter().any(|c| c.name == column.name))
+        .collect();
+
+    pair(
+        &base_columns,
+        &target_columns,
+        |base_column, target_column| {
+            column_score(base_table, target_table, base_column, target_column)
+        },
+    )
+}
+
+fn column_score(
+    base_table: &Table,
+    target_table: &Table,
+    base_column: &Column,
+    target_column: &Column,
+) -> f64 {
+    let is_checked = |property| !base_column.unchecked.contains(&property);
+
+    if is_checked(Property::DataType) && base_column.data_type != target_column.data_type {
+        return 0.0;
+    }
+
+    let position_score = 1.0
+        - (relative_position(base_table, base_column)
+            - relative_position(target_table, target_column))
+        .abs();
+
+    let attribute_score = if (!is_checked(Property::Nullable)
+        || base_column.nullable == target_column.nullable)
+        && (!is_checked(Property::Default) || base_column.default == target_column.default)
+    {
+        1.0
+    } else {
+        0.0
+    };
+
+    let base_constraints = constraint_signature(base_table, base_column);
+    let target_constraints = constraint_signature(target_table, target_column);
+
+    let constraint_score = if base_constraints.is_empty() && target_constraints.is_empty() {
+        0.5
+    } else if base_constraints == target_constraints {
+        1.0
+    } else {
+        0.0
+    };
+
+    0.5 * name_similarity(&base_column.name, &target_column.name)
+        + 0.2 * position_score
+        + 0.15 * attribute_score
+        + 0.15 * constraint_score
+}
+
+// 테이블 안에서 컬럼의 상대적인 위치 (0.0 ~ 1.0)
+fn relative_position(table: &Table, column: &Column) -> f64 {
+    let index = table
+        .columns
+        .iter()
+        .position(|c| c.name == column.name)
+        .unwrap_or_default();
+
+    index as f64 / table.columns.len().max(1) as f64
+}
+
+// 컬럼이 속한 인덱스와 외래키를 이름 없이 나타낸 목록
+fn constraint_signature(table: &Table, column: &Column) -> Vec<String> {
+    let mut signature = vec![];
+
+    for index in &table.indexes {
+        if let Some(position) = index.columns.iter().position(|c| c == &column.name) {
+            let unique = if index.is_unique { "unique " } else { "" };
+            signature.push(format!("{unique}index {position}/{}", index.columns.len()));
+        }
+    }
+
+    for foreign_key in table.foreign_keys() {
+        if foreign_key.column.contains(&column.name) {
+            signature.push(format!(
+                "references {}.{}",
+                foreign_key.foreign_column.table_name, foreign_key.foreign_column.column_name
+            ));
+        }
+    }
+
+    signature.sort();
+
+    signature
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::{Constraint, ForeignKey, Index, SelectColumn};
+
+    fn column(name: &str, data_type: &str) -> Column {
+        Column {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn table(name: &str, columns: &[(&str, &str)]) -> Table {
+        Table {
+            name: name.to_string(),
+            columns: columns
+                .iter()
+                .map(|(name, data_type)| column(name, data_type))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn pair_uses_confidence_threshold() {
+        let base = [1, 2];
+        let target = [10, 20];
+        let base_list: Vec<&i32> = base.iter().collect();
+        let target_list: Vec<&i32> = target.iter().collect();
+
+        // 기준값과 같은 점수는 짝을 이루고, 조금이라도 낮으면 짝을 이루지 않습니다.
+        let renames = pair(&base_list, &target_list, |base, target| {
+            match (base, target) {
+                (1, 10) => CONFIDENCE_THRESHOLD,
+                (2, 20) => CONFIDENCE_THRESHOLD - 0.001,
+                _ => 0.0,
+            }
+        });
+
+        assert_eq!(renames.len(), 1);
+        assert_eq!(*renames[0].base, 1);
+        assert_eq!(*renames[0].target, 10);
+    }
+
+    #[test]
+    fn pair_picks_highest_confidence_first() {
+        let base = [1, 2];
+        let target = [10, 20];
+        let base_list: Vec<&i32> = base.iter().collect();
+        let target_list: Vec<&i32> = target.iter().collect();
+
+        // 1은 10, 20 모두와 짝을 이룰 수 있지만, 점수가 더 높은 2-10이 먼저 고른 10은 다시 고르지 않습니다.
+        let renames = pair(&base_list, &target_list, |base, target| {
+            match (base, target) {
+                (1, 10) => 0.8,
+                (1, 20) => 0.7,
+                (2, 10) => 0.9,
+                _ => 0.0,
+            }
+        });
+
+        let pairs: Vec<(i32, i32)> = renames
+            .iter()
+            .map(|rename| (*rename.base, *rename.target))
+            .collect();
+        assert_eq!(pairs, [(2, 10), (1, 20)]);
+        assert_close(find_rename(&renames, &base[0]).unwrap().confidence, 0.7);
+        assert!(is_renamed_target(&renames, &target[1]));
+    }
+
+    #[test]
+    fn table_score_weights() {
+        let columns = [
+            ("id", "bigint"),
+            ("email", "text"),
+            ("name", "text"),
+            ("created_at", "timestamp"),
+        ];
+
+        // 컬럼 구성이 같고(0.6) 이름도 같다면(0.4) 1.0입니다.
+        assert_close(
+            table_score(&table("users", &columns), &table("users", &columns)),
+            1.0,
+        );
+
+        // 컬럼 구성이 같다면, 이름 유사도는 0.4만큼만 반영됩니다.
+        let similarity = name_similarity("users", "accounts");
+        assert_close(
+            table_score(&table("users", &columns), &table("accounts", &columns)),
+            0.6 + 0.4 * similarity,
+        );
+
+        // 겹치는 컬럼이 없다면 이름 점수만 남으므로, 이름이 같아도 기준값보다 낮습니다.
+        let score = table_score(
+            &table("users", &columns),
+            &table("users", &[("uuid", "uuid")]),
+        );
+        assert_close(score, 0.4);
+        assert!(score < CONFIDENCE_THRESHOLD);
+    }
+
+    #[test]
+    fn table_score_discounts_small_tables() {
+        // 컬럼이 4개보다 적다면, 같은 구성이라도 컬럼 점수를 낮춥니다. (2/4)
+        let columns = [("id", "bigint"), ("name", "text")];
+        let similarity = name_similarity("tags", "labels");
+
+        assert_close(
+            table_score(&table("tags", &columns), &table("labels", &columns)),
+            0.6 * 0.5 + 0.4 * similarity,
+        );
+    }
+
+    #[test]
+    fn pair_tables_pairs_renamed_table() {
+        let columns = [
+            ("id", "bigint"),
+            ("email", "text"),
+            ("name", "text"),
+            ("created_at", "timestamp"),
+        ];
+        let users = table("users", &columns);
+        let orders = table("orders", &[("id", "bigint"), ("total", "numeric")]);
+        let accounts = table("accounts", &columns);
+
+        let renames = pair_tables(&[&users, &orders], &[&accounts]);
+
+        assert_eq!(renames.len(), 1);
+        assert_eq!(renames[0].base.name, "users");
+        assert_eq!(renames[0].target.name, "accounts");
+    }
+
+    #[test]
+    fn column_score_weights() {
+        let base_table = table("users", &[("id", "bigint"), ("email", "text")]);
+        let target_table = table("users", &[("id", "bigint"), ("email", "text")]);
+
+        // 이름(0.5), 위치(0.2), NULLABLE과 DEFAULT(0.15)가 같고, 양쪽 모두 제약조건이 없다면(0.15 * 0.5)
+        assert_close(
+            column_score(
+                &base_table,
+                &target_table,
+                &base_table.columns[1],
+                &target_table.columns[1],
+            ),
+            0.5 + 0.2 + 0.15 + 0.075,
+        );
+
+        // 타입이 다르다면 0점입니다.
+        let target_table = table("users", &[("id", "bigint"), ("email", "varchar(320)")]);
+        assert_close(
+            column_score(
+                &base_table,
+                &target_table,
+                &base_table.columns[1],
+                &target_table.columns[1],
+            ),
+            0.0,
+        );
+
+        // NULLABLE이 다르다면 속성 점수(0.15)가 빠집니다.
+        let mut target_table = table("users", &[("id", "bigint"), ("email", "text")]);
+        target_table.columns[1].nullable = true;
+        assert_close(
+            column_score(
+                &base_table,
+                &target_table,
+                &base_table.columns[1],
+                &target_table.columns[1],
+            ),
+            0.5 + 0.2 + 0.075,
+        );
+    }
+
+    #[test]
+    fn column_score_constraints() {
+        let mut base_table = table("posts", &[("id", "bigint"), ("user_id", "bigint")]);
+        base_table.indexes.push(Index {
+            name: "posts_user_id_idx".to_string(),
+            columns: vec!["user_id".to_string()],
+            ..Default::default()
+        });
+        base_table
+            .constraints
+            .push(Constraint::ForeignKey(ForeignKey {
+                name: "posts_user_id_fkey".to_string(),
+                column: vec!["user_id".to_string()],
+                foreign_column: SelectColumn {
+                    table_name: "users".to_string(),
+                    column_name: "id".to_string(),
+                },
+            }));
+
+        // 인덱스와 외래키 구성이 같다면, 이름이 달라도 제약조건 점수(0.15)를 모두 받습니다.
+        let mut target_table = base_table.clone();
+        target_table.columns[1].name = "author_id".to_string();
+        target_table.indexes[0].columns = vec!["author_id".to_string()];
+        let Constraint::ForeignKey(foreign_key) = &mut target_table.constraints[0];
+        foreign_key.column = vec!["author_id".to_string()];
+
+        let similarity = name_similarity("user_id", "author_id");
+        assert_close(
+            column_score(
+                &base_table,
+                &target_table,
+                &base_table.columns[1],
+                &target_table.columns[1],
+            ),
+            0.5 * similarity + 0.2 + 0.15 + 0.15,
+        );
+
+        // 한쪽에만 제약조건이 있다면 제약조건 점수는 0입니다.
+        target_table.indexes.clear();
+        target_table.constraints.clear();
+        assert_close(
+            column_score(
+                &base_table,
+                &target_table,
+                &base_table.columns[1],
+                &target_table.columns[1],
+            ),
+            0.5 * similarity + 0.2 + 0.15,
+        );
+    }
+
+    #[test]
+    fn pair_columns_only_pairs_unmatched_columns() {
+        let base_table = table(
+            "users",
+            &[("id", "bigint"), ("email", "text"), ("age", "integer")],
+        );
+        let target_table = table(
+            "users",
+            &[
+                ("id", "bigint"),
+                ("email_address", "text"),
+                ("nickname", "text"),
+            ],
+        );
+
+        let renames = pair_columns(&base_table, &target_table);
+
+        // age는 같은 타입의 짝이 없으므로 짝을 이루지 않습니다.
+        assert_eq!(renames.len(), 1);
+        assert_eq!(renames[0].base.name, "email");
+        assert_eq!(renames[0].target.name, "email_address");
+    }
+}
@@ -0,0 +1,64 @@
+use clap::{Args, Subcommand};
+use magiceye::sql::secret::redact_url;
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum ConfigAction {
+    #[clap(about = "list database pairs and comparison groups")]
+    List,
+    #[clap(about = "print the config, or a single pair or group")]
+    Show(ShowFlags),
+    #[clap(about = "set a config value by its path (e.g. database_pairs.prod.pool_size 10)")]
+    Set(SetFlags),
+    #[clap(about = "remove a database pair")]
+    RemovePair(RemovePairFlags),
+    #[clap(about = "check the config for problems and try to connect to every database")]
+    Validate(ValidateFlags),
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct ValidateFlags {
+    // 연결하지 않고 설정 값만 확인합니다.
+    #[clap(long, help = "check the config file only, without connecting")]
+    pub offline: bool,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct ShowFlags {
+    // 없다면 전체 설정을 출력합니다.
+    #[clap(help = "name of the pair or group to show")]
+    pub name: Option<String>,
+}
+
+// 로그에 비밀번호가 남지 않도록 Debug는 직접 구현합니다.
+#[derive(Clone, Args)]
+pub struct SetFlags {
+    // "."으로 구분한 경로. 배열의 항목은 name 또는 순서로 찾습니다.
+    #[clap(help = "path of the value (e.g. current_language, database_pairs.prod.bidirectional)")]
+    pub path: String,
+
+    // JSON으로 해석할 수 있다면 JSON 값으로, 아니라면 문자열로 저장합니다.
+    #[clap(help = "new value (parsed as JSON when possible, otherwise used as a string)")]
+    pub value: String,
+}
+
+impl std::fmt::Debug for SetFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SetFlags")
+            .field("path", &self.path)
+            .field("value", &redact_url(&self.value))
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct RemovePairFlags {
+    #[clap(help = "name of the pair to remove")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(name = "config", about = "show or edit magiceye config")]
+pub struct Command {
+    #[clap(subcommand)]
+    pub action: ConfigAction,
+}
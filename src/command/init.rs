@@ -1,9 +1,98 @@
 use serde::Deserialize;
 
 use clap::Args;
+use magiceye::{
+    config::{DatabaseType, Language, TlsMode},
+    sql::secret::redact_url,
+};
 
-#[derive(Clone, Debug, Default, Deserialize, Args)]
-pub struct CommandFlags {}
+// --pair를 제외한 인자가 하나라도 있다면 화면 없이 바로 저장합니다.
+// 로그에 비밀번호가 남지 않도록 Debug는 직접 구현합니다.
+#[derive(Clone, Default, Deserialize, Args)]
+pub struct CommandFlags {
+    // 추가하거나 수정할 데이터베이스 쌍의 이름. 없다면 기본 쌍을 수정합니다.
+    #[clap(long, help = "name of the database pair to add or edit")]
+    pub pair: Option<String>,
+
+    #[clap(long = "type", help = "database type (postgres, mysql)")]
+    pub database_type: Option<DatabaseType>,
+
+    #[clap(long, help = "connection URL of the base database (or file:<path>)")]
+    pub base_url: Option<String>,
+
+    #[clap(long, help = "connection URL of the target database")]
+    pub target_url: Option<String>,
+
+    #[clap(long, help = "language of the report (english, korean)")]
+    pub language: Option<Language>,
+
+    #[clap(
+        long,
+        help = "TLS mode of the base database (disable, prefer, require, verify-ca, verify-full)"
+    )]
+    pub base_tls_mode: Option<TlsMode>,
+
+    #[clap(long, help = "CA certificate file to verify the base database")]
+    pub base_tls_root_cert: Option<String>,
+
+    #[clap(long, help = "client certificate file for the base database")]
+    pub base_tls_client_cert: Option<String>,
+
+    #[clap(long, help = "client key file for the base database")]
+    pub base_tls_client_key: Option<String>,
+
+    #[clap(
+        long,
+        help = "TLS mode of the target database (disable, prefer, require, verify-ca, verify-full)"
+    )]
+    pub target_tls_mode: Option<TlsMode>,
+
+    #[clap(long, help = "CA certificate file to verify the target database")]
+    pub target_tls_root_cert: Option<String>,
+
+    #[clap(long, help = "client certificate file for the target database")]
+    pub target_tls_client_cert: Option<String>,
+
+    #[clap(long, help = "client key file for the target database")]
+    pub target_tls_client_key: Option<String>,
+}
+
+impl std::fmt::Debug for CommandFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommandFlags")
+            .field("pair", &self.pair)
+            .field("database_type", &self.database_type)
+            .field("base_url", &self.base_url.as_deref().map(redact_url))
+            .field("target_url", &self.target_url.as_deref().map(redact_url))
+            .field("language", &self.language)
+            .field("base_tls_mode", &self.base_tls_mode)
+            .field("base_tls_root_cert", &self.base_tls_root_cert)
+            .field("base_tls_client_cert", &self.base_tls_client_cert)
+            .field("base_tls_client_key", &self.base_tls_client_key)
+            .field("target_tls_mode", &self.target_tls_mode)
+            .field("target_tls_root_cert", &self.target_tls_root_cert)
+            .field("target_tls_client_cert", &self.target_tls_client_cert)
+            .field("target_tls_client_key", &self.target_tls_client_key)
+            .finish()
+    }
+}
+
+impl CommandFlags {
+    pub fn is_interactive(&self) -> bool {
+        self.database_type.is_none()
+            && self.base_url.is_none()
+            && self.target_url.is_none()
+            && self.language.is_none()
+            && self.base_tls_mode.is_none()
+            && self.base_tls_root_cert.is_none()
+            && self.base_tls_client_cert.is_none()
+            && self.base_tls_client_key.is_none()
+            && self.target_tls_mode.is_none()
+            && self.target_tls_root_cert.is_none()
+            && self.target_tls_client_cert.is_none()
+            && self.target_tls_client_key.is_none()
+    }
+}
 
 #[derive(Clone, Debug, Args)]
 #[clap(name = "init", about = "initialize magiceye config")]
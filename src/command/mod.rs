@@ -1,3 +1,4 @@
+pub mod config;
 pub mod init;
 pub mod run;
 
@@ -8,10 +9,19 @@ use clap::Parser;
 pub struct Command {
     #[clap(subcommand)]
     pub action: SubCommand,
+
+    // 프로젝트 설정 파일 경로. MAGICEYE_CONFIG 환경변수보다 우선합니다.
+    #[clap(
+        long,
+        global = true,
+        help = "config file to use over the user config (default: MAGICEYE_CONFIG, or the nearest magiceye.toml / .magiceye.json)"
+    )]
+    pub config: Option<String>,
 }
 
 #[derive(clap::Subcommand, Debug)]
 pub enum SubCommand {
     Run(run::Command),
     Init(init::Command),
+    Config(config::Command),
 }
@@ -2,8 +2,72 @@ use serde::Deserialize;
 
 use clap::Args;
 
+use magiceye::report::format::ReportFormat;
+
 #[derive(Clone, Debug, Default, Deserialize, Args)]
-pub struct CommandFlags {}
+pub struct CommandFlags {
+    // 기본 쌍 대신 database_pairs에서 이름으로 선택한 쌍을 비교합니다.
+    #[clap(long, help = "name of the database pair to compare")]
+    pub pair: Option<String>,
+
+    // 모든 쌍을 차례로 비교하고, 쌍마다 보고서를 생성합니다.
+    #[clap(
+        long,
+        conflicts_with = "pair",
+        help = "compare every database pair, one report per pair"
+    )]
+    pub all_pairs: bool,
+
+    // 하나의 base를 여러 target 환경과 비교하고, 매트릭스 보고서를 생성합니다.
+    #[clap(
+        long,
+        conflicts_with_all = ["pair", "all_pairs"],
+        help = "name of the comparison group to compare (one base, many targets)"
+    )]
+    pub group: Option<String>,
+
+    // 이미 확인한 차이를 기록한 baseline 파일 경로
+    #[clap(
+        long,
+        help = "baseline file of acknowledged differences ({pair} is replaced with the pair name)"
+    )]
+    pub baseline: Option<String>,
+
+    // 이번 실행의 모든 차이를 baseline 파일에 기록합니다.
+    #[clap(
+        long,
+        help = "record every difference of this run in the baseline file"
+    )]
+    pub update_baseline: bool,
+
+    // TUI 없이 로그로 진행상황을 출력하고, 결과에 따라 종료 코드를 반환합니다.
+    #[clap(
+        long,
+        help = "run without the interactive view and exit non-zero when differences at or above fail_on are found"
+    )]
+    pub ci: bool,
+
+    // 보고서 파일 이름 템플릿. -라면 파일 대신 표준 출력에 씁니다.
+    #[clap(
+        long,
+        help = "report file name template ({pair}, {timestamp} and {ext} are replaced), or - for stdout"
+    )]
+    pub output: Option<String>,
+
+    // 보고서 파일을 저장할 디렉터리
+    #[clap(
+        long,
+        help = "directory to save report files in (default: current directory)"
+    )]
+    pub output_dir: Option<String>,
+
+    // 여러 번 지정하면 형식마다 보고서를 생성합니다.
+    #[clap(
+        long,
+        help = "report format (json, markdown), can be given more than once"
+    )]
+    pub format: Vec<ReportFormat>,
+}
 
 #[derive(Clone, Debug, Args)]
 #[clap(name = "run", about = "run magiceye")]
@@ -1,11 +1,88 @@
+use std::{collections::HashMap, str::FromStr};
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+use crate::sql::{secret::redact_url, service};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CheckType {
     CommentOfColumn,
     CommentOfTable,
     TypeOfColumn,
     IndexOfTable,
+    ForeignKeyOfTable,
+    NullableOfColumn,
+    DefaultOfColumn,
+    AutoIncrementOfColumn,
+    // 컬럼 자체가 없거나 추가된 경우
+    ColumnOfTable,
+    // 테이블 자체가 없거나 추가된 경우
+    Table,
+}
+
+impl CheckType {
+    // 설정 파일의 severity에 없는 경우 사용하는 심각도
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            Self::Table | Self::ColumnOfTable | Self::TypeOfColumn => Severity::Error,
+            Self::NullableOfColumn
+            | Self::DefaultOfColumn
+            | Self::AutoIncrementOfColumn
+            | Self::IndexOfTable
+            | Self::ForeignKeyOfTable => Severity::Warning,
+            Self::CommentOfColumn | Self::CommentOfTable => Severity::Info,
+        }
+    }
+}
+
+// 차이의 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+// 비교 결과에서 제외할 항목입니다.
+// pattern은 테이블/컬럼/인덱스 경로("table", "table.column")에 대한 glob 패턴이며, *와 ?를 지원합니다.
+// 예전 형식처럼 "CommentOfColumn" 만 적으면 모든 경로에 적용됩니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(from = "IgnoreRuleFormat")]
+pub struct IgnoreRule {
+    pub check_type: CheckType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IgnoreRuleFormat {
+    CheckType(CheckType),
+    Rule {
+        check_type: CheckType,
+        #[serde(default)]
+        pattern: Option<String>,
+    },
+}
+
+impl From<IgnoreRuleFormat> for IgnoreRule {
+    fn from(format: IgnoreRuleFormat) -> Self {
+        match format {
+            IgnoreRuleFormat::CheckType(check_type) => Self {
+                check_type,
+                pattern: None,
+            },
+            IgnoreRuleFormat::Rule {
+                check_type,
+                pattern,
+            } => Self {
+                check_type,
+                pattern,
+            },
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -34,12 +111,28 @@ impl Language {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Language {
     fn default() -> Self {
         Self::English
     }
 }
 
+// 명령행 인자로 받을 때 사용합니다. (예: --language korean)
+impl FromStr for Language {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "english" | "en" => Ok(Self::English),
+            "korean" | "ko" => Ok(Self::Korean),
+            _ => Err(anyhow::anyhow!(
+                "unknown language: {value} (expected english or korean)"
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum DatabaseType {
     Postgres,
@@ -66,18 +159,179 @@ impl DatabaseType {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for DatabaseType {
     fn default() -> Self {
         Self::Postgres
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+// 명령행 인자로 받을 때 사용합니다. (예: --type mysql)
+impl FromStr for DatabaseType {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "postgres" | "postgresql" => Ok(Self::Postgres),
+            "mysql" => Ok(Self::Mysql),
+            _ => Err(anyhow::anyhow!(
+                "unknown database type: {value} (expected postgres or mysql)"
+            )),
+        }
+    }
+}
+
+impl DatabaseType {
+    // 커넥션 URL로 사용할 수 있는 scheme 목록. file:은 모든 타입에서 사용할 수 있습니다.
+    fn url_schemes(&self) -> &'static [&'static str] {
+        match self {
+            Self::Postgres => &["postgres://", "postgresql://"],
+            Self::Mysql => &["mysql://", "mariadb://"],
+        }
+    }
+}
+
+// 로그에 비밀번호가 남지 않도록 Debug는 직접 구현합니다.
+#[derive(Serialize, Deserialize, Clone)]
 pub struct DatabasePair {
     pub name: String,
     pub database_type: DatabaseType,
     pub base_connection: String,
     pub target_connection: String,
+    // true라면 target에만 있는 테이블, 컬럼, 인덱스, 외래키도 보고합니다.
+    #[serde(default)]
+    pub bidirectional: bool,
+    // 데이터베이스별 최대 커넥션 수. 테이블 정보를 동시에 조회하는 개수이기도 합니다.
+    #[serde(default = "default_pool_size")]
+    pub pool_size: u32,
+    // 운영 데이터베이스를 안전하게 조회하기 위한 세션 설정
+    #[serde(default)]
+    pub session: SessionOptions,
+    // 이 데이터베이스 쌍에만 적용되는 무시 규칙
+    #[serde(default)]
+    pub ignore_list: Vec<IgnoreRule>,
+    // 비밀번호를 출력하는 셸 명령. 있다면 URL의 비밀번호 대신 사용합니다. (예: pass show db/prod)
+    #[serde(default)]
+    pub base_password_command: Option<String>,
+    #[serde(default)]
+    pub target_password_command: Option<String>,
+    #[serde(default)]
+    pub base_tls: TlsOptions,
+    #[serde(default)]
+    pub target_tls: TlsOptions,
+    #[serde(default)]
+    pub base_ssh_tunnel: Option<SshTunnelOptions>,
+    #[serde(default)]
+    pub target_ssh_tunnel: Option<SshTunnelOptions>,
+}
+
+impl std::fmt::Debug for DatabasePair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabasePair")
+            .field("name", &self.name)
+            .field("database_type", &self.database_type)
+            .field("base_connection", &redact_url(&self.base_connection))
+            .field("target_connection", &redact_url(&self.target_connection))
+            .field("bidirectional", &self.bidirectional)
+            .field("pool_size", &self.pool_size)
+            .field("session", &self.session)
+            .field("ignore_list", &self.ignore_list)
+            .field("base_password_command", &self.base_password_command)
+            .field("target_password_command", &self.target_password_command)
+            .field("base_tls", &self.base_tls)
+            .field("target_tls", &self.target_tls)
+            .field("base_ssh_tunnel", &self.base_ssh_tunnel)
+            .field("target_ssh_tunnel", &self.target_ssh_tunnel)
+            .finish()
+    }
+}
+
+fn default_pool_size() -> u32 {
+    5
+}
+
+impl DatabasePair {
+    // 설정 값의 문제를 찾아서 사람이 읽을 수 있는 문장으로 반환합니다. 실제로 연결해보지는 않습니다.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = vec![];
+
+        if self.name.is_empty() {
+            problems.push("name is empty".to_string());
+        }
+
+        for (side, connection) in [
+            ("base_connection", &self.base_connection),
+            ("target_connection", &self.target_connection),
+        ] {
+            if let Some(problem) = validate_connection(&self.database_type, side, connection) {
+                problems.push(problem);
+            }
+        }
+
+        if self.pool_size == 0 {
+            problems.push("pool_size must be greater than 0".to_string());
+        }
+
+        problems.extend(self.base_tls.validate("base_tls", &self.base_connection));
+        problems.extend(
+            self.target_tls
+                .validate("target_tls", &self.target_connection),
+        );
+
+        for (side, connection, tls, ssh_tunnel) in [
+            (
+                "base_ssh_tunnel",
+                &self.base_connection,
+                &self.base_tls,
+                &self.base_ssh_tunnel,
+            ),
+            (
+                "target_ssh_tunnel",
+                &self.target_connection,
+                &self.target_tls,
+                &self.target_ssh_tunnel,
+            ),
+        ] {
+            if let Some(ssh_tunnel) = ssh_tunnel {
+                problems.extend(ssh_tunnel.validate(side));
+
+                if connection.starts_with("file:") {
+                    problems.push(format!("{side} cannot be used with a file: connection"));
+                }
+
+                problems.extend(validate_tunnel_tls(side, tls, connection));
+            }
+        }
+
+        problems
+    }
+}
+
+fn validate_connection(
+    database_type: &DatabaseType,
+    side: &str,
+    connection: &str,
+) -> Option<String> {
+    if connection.is_empty() {
+        return Some(format!("{side} is empty"));
+    }
+
+    let reference_prefix = service::reference_prefix(database_type);
+
+    if connection.starts_with("file:")
+        || connection.starts_with(reference_prefix)
+        || database_type
+            .url_schemes()
+            .iter()
+            .any(|scheme| connection.starts_with(scheme))
+    {
+        return None;
+    }
+
+    Some(format!(
+        "{side} does not look like a {database_type:?} connection URL (expected {}, {reference_prefix}<name> or file:<path>)",
+        database_type.url_schemes().join(", ")
+    ))
 }
 
 impl Default for DatabasePair {
@@ -87,14 +341,789 @@ impl Default for DatabasePair {
             database_type: DatabaseType::Postgres,
             base_connection: String::new(),
             target_connection: String::new(),
+            bidirectional: false,
+            pool_size: default_pool_size(),
+            session: SessionOptions::default(),
+            ignore_list: vec![],
+            base_password_command: None,
+            target_password_command: None,
+            base_tls: TlsOptions::default(),
+            target_tls: TlsOptions::default(),
+            base_ssh_tunnel: None,
+            target_ssh_tunnel: None,
+        }
+    }
+}
+
+// 하나의 base를 여러 target 환경과 한 번에 비교하는 그룹입니다.
+// base는 한 번만 조회하고, 결과는 환경별 일치 여부를 나타내는 매트릭스로 보고합니다.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ComparisonGroup {
+    pub name: String,
+    pub database_type: DatabaseType,
+    pub base_connection: String,
+    pub targets: Vec<TargetEnvironment>,
+    // 아래는 모든 target 환경에 공통으로 적용되는 DatabasePair의 설정입니다.
+    #[serde(default)]
+    pub bidirectional: bool,
+    #[serde(default = "default_pool_size")]
+    pub pool_size: u32,
+    #[serde(default)]
+    pub session: SessionOptions,
+    #[serde(default)]
+    pub ignore_list: Vec<IgnoreRule>,
+    #[serde(default)]
+    pub base_password_command: Option<String>,
+    #[serde(default)]
+    pub base_tls: TlsOptions,
+    #[serde(default)]
+    pub base_ssh_tunnel: Option<SshTunnelOptions>,
+}
+
+impl std::fmt::Debug for ComparisonGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComparisonGroup")
+            .field("name", &self.name)
+            .field("database_type", &self.database_type)
+            .field("base_connection", &redact_url(&self.base_connection))
+            .field("targets", &self.targets)
+            .field("bidirectional", &self.bidirectional)
+            .field("pool_size", &self.pool_size)
+            .field("session", &self.session)
+            .field("ignore_list", &self.ignore_list)
+            .field("base_password_command", &self.base_password_command)
+            .field("base_tls", &self.base_tls)
+            .field("base_ssh_tunnel", &self.base_ssh_tunnel)
+            .finish()
+    }
+}
+
+// 비교 그룹의 target 환경 (예: dev, qa, stage)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TargetEnvironment {
+    pub name: String,
+    pub connection: String,
+    #[serde(default)]
+    pub password_command: Option<String>,
+    #[serde(default)]
+    pub tls: TlsOptions,
+    #[serde(default)]
+    pub ssh_tunnel: Option<SshTunnelOptions>,
+}
+
+impl std::fmt::Debug for TargetEnvironment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TargetEnvironment")
+            .field("name", &self.name)
+            .field("connection", &redact_url(&self.connection))
+            .field("password_command", &self.password_command)
+            .field("tls", &self.tls)
+            .field("ssh_tunnel", &self.ssh_tunnel)
+            .finish()
+    }
+}
+
+impl ComparisonGroup {
+    // base를 조회하기 위한 데이터베이스 쌍. target_connection은 비어 있습니다.
+    pub fn base_pair(&self) -> DatabasePair {
+        DatabasePair {
+            name: self.name.clone(),
+            database_type: self.database_type.clone(),
+            base_connection: self.base_connection.clone(),
+            target_connection: String::new(),
+            bidirectional: self.bidirectional,
+            pool_size: self.pool_size,
+            session: self.session.clone(),
+            ignore_list: self.ignore_list.clone(),
+            base_password_command: self.base_password_command.clone(),
+            target_password_command: None,
+            base_tls: self.base_tls.clone(),
+            target_tls: TlsOptions::default(),
+            base_ssh_tunnel: self.base_ssh_tunnel.clone(),
+            target_ssh_tunnel: None,
+        }
+    }
+
+    // base와 target 환경 하나를 비교하는 데이터베이스 쌍. 이름은 "그룹.환경" 형태입니다.
+    pub fn target_pair(&self, target: &TargetEnvironment) -> DatabasePair {
+        DatabasePair {
+            name: format!("{}.{}", self.name, target.name),
+            target_connection: target.connection.clone(),
+            target_password_command: target.password_command.clone(),
+            target_tls: target.tls.clone(),
+            target_ssh_tunnel: target.ssh_tunnel.clone(),
+            ..self.base_pair()
         }
     }
 }
 
+// 모든 커넥션은 읽기 전용으로 열리며, 아래 제한을 넘는 조회는 락을 잡고 기다리는 대신 실패합니다.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct SessionOptions {
+    // 쿼리 하나의 최대 실행 시간 (PostgreSQL: statement_timeout, MySQL: max_execution_time)
+    pub statement_timeout_ms: u64,
+    // 락을 기다리는 최대 시간 (PostgreSQL: lock_timeout, MySQL: lock_wait_timeout)
+    pub lock_timeout_ms: u64,
+    // PostgreSQL의 application_name. pg_stat_activity에서 확인할 수 있습니다.
+    pub application_name: String,
+    // 초당 최대 카탈로그 쿼리 수. 없으면 제한하지 않습니다.
+    pub max_queries_per_second: Option<u32>,
+}
+
+impl Default for SessionOptions {
+    fn default() -> Self {
+        Self {
+            statement_timeout_ms: 30_000,
+            lock_timeout_ms: 5_000,
+            application_name: "magiceye".to_string(),
+            max_queries_per_second: None,
+        }
+    }
+}
+
+// 커넥션의 TLS 설정. 없는 값은 커넥션 URL(sslmode, ssl-mode 등)이나 드라이버의 기본값을 사용합니다.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct TlsOptions {
+    pub mode: Option<TlsMode>,
+    // 서버 인증서를 검증할 CA 인증서 경로 (PostgreSQL: sslrootcert, MySQL: ssl-ca)
+    pub root_cert: Option<String>,
+    // 클라이언트 인증서와 키 경로. 둘 다 있어야 합니다.
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+}
+
+impl TlsOptions {
+    // 설정 값의 문제를 찾습니다. side는 문장 앞에 붙일 이름입니다. (예: base_tls)
+    // connection은 이 설정을 적용할 커넥션 URL이며, URL에 있는 인증서도 확인합니다.
+    pub fn validate(&self, side: &str, connection: &str) -> Vec<String> {
+        let mut problems = vec![];
+
+        // 서버 인증서를 검증하는 모드라면, 검증에 사용할 CA 인증서가 있어야 합니다.
+        if let Some(mode @ (TlsMode::VerifyCa | TlsMode::VerifyFull)) = self.mode {
+            let has_root_cert_in_url = url::Url::parse(connection).is_ok_and(|url| {
+                url.query_pairs()
+                    .any(|(key, _)| matches!(&*key, "sslrootcert" | "sslca" | "ssl-ca"))
+            });
+
+            if self.root_cert.is_none() && !has_root_cert_in_url {
+                problems.push(format!(
+                    "{side}: mode {} requires root_cert, the CA certificate that signed the server certificate",
+                    mode.as_str()
+                ));
+            }
+        }
+
+        if self.client_cert.is_some() != self.client_key.is_some() {
+            problems.push(format!(
+                "{side}: client_cert and client_key must be set together"
+            ));
+        }
+
+        for (name, path) in [
+            ("root_cert", &self.root_cert),
+            ("client_cert", &self.client_cert),
+            ("client_key", &self.client_key),
+        ] {
+            if let Some(path) = path {
+                if !std::path::Path::new(path).is_file() {
+                    problems.push(format!("{side}.{name}: file not found ({path})"));
+                }
+            }
+        }
+
+        problems
+    }
+
+    // 서버 인증서의 호스트 이름까지 확인하는지 여부. mode가 없다면 커넥션 URL의 sslmode, ssl-mode를 확인합니다.
+    pub fn verifies_host_name(&self, connection: &str) -> bool {
+        let mode = match self.mode {
+            Some(mode) => Some(mode),
+            None => url::Url::parse(connection).ok().and_then(|url| {
+                url.query_pairs()
+                    .find(|(key, _)| key == "sslmode" || key == "ssl-mode")
+                    .and_then(|(_, value)| TlsMode::from_str(&value).ok())
+            }),
+        };
+
+        mode == Some(TlsMode::VerifyFull)
+    }
+}
+
+// ssh 터널을 거치면 127.0.0.1로 연결하므로, 서버 인증서의 호스트 이름도 127.0.0.1과 비교하게 됩니다.
+// 드라이버에는 인증서를 확인할 이름을 따로 지정하는 방법이 없으므로, verify-full과 ssh 터널은 함께 사용할 수 없습니다.
+pub fn validate_tunnel_tls(side: &str, tls: &TlsOptions, connection: &str) -> Option<String> {
+    if !tls.verifies_host_name(connection) {
+        return None;
+    }
+
+    Some(format!(
+        "{side}: TLS mode verify-full (VERIFY_IDENTITY) cannot be used through an ssh tunnel, because the server certificate would be checked against 127.0.0.1 (use verify-ca instead)"
+    ))
+}
+
+// PostgreSQL의 sslmode 이름을 사용합니다. MySQL의 ssl-mode 이름으로도 적을 수 있습니다.
+// verify-full은 MySQL의 VERIFY_IDENTITY에 해당합니다.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TlsMode {
+    #[serde(alias = "disabled")]
+    Disable,
+    #[serde(alias = "preferred")]
+    Prefer,
+    #[serde(alias = "required")]
+    Require,
+    VerifyCa,
+    #[serde(alias = "verify-identity")]
+    VerifyFull,
+}
+
+impl TlsMode {
+    pub fn list() -> Vec<Self> {
+        vec![
+            Self::Disable,
+            Self::Prefer,
+            Self::Require,
+            Self::VerifyCa,
+            Self::VerifyFull,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::Prefer => "prefer",
+            Self::Require => "require",
+            Self::VerifyCa => "verify-ca",
+            Self::VerifyFull => "verify-full",
+        }
+    }
+}
+
+// 명령행 인자로 받을 때 사용합니다. (예: --base-tls-mode verify-full)
+impl FromStr for TlsMode {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().replace('_', "-").as_str() {
+            "disable" | "disabled" => Ok(Self::Disable),
+            "prefer" | "preferred" => Ok(Self::Prefer),
+            "require" | "required" => Ok(Self::Require),
+            "verify-ca" => Ok(Self::VerifyCa),
+            "verify-full" | "verify-identity" => Ok(Self::VerifyFull),
+            _ => Err(anyhow::anyhow!(
+                "unknown TLS mode: {value} (expected disable, prefer, require, verify-ca or verify-full)"
+            )),
+        }
+    }
+}
+
+// 배스천 호스트를 거쳐서 데이터베이스에 연결하기 위한 SSH 터널 설정입니다. (ssh -L)
+// 실행할 때 터널을 열고, 비교가 끝나면 닫습니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SshTunnelOptions {
+    pub host: String,
+    #[serde(default = "default_ssh_port")]
+    pub port: u16,
+    // 없다면 ssh 설정(~/.ssh/config)이나 현재 사용자를 사용합니다.
+    #[serde(default)]
+    pub user: Option<String>,
+    #[serde(default)]
+    pub key_file: Option<String>,
+    // 없다면 ~/.ssh/known_hosts를 사용합니다. 모르는 호스트에는 연결하지 않습니다.
+    #[serde(default)]
+    pub known_hosts_file: Option<String>,
+}
+
+fn default_ssh_port() -> u16 {
+    22
+}
+
+impl SshTunnelOptions {
+    pub fn validate(&self, side: &str) -> Vec<String> {
+        let mut problems = vec![];
+
+        if self.host.is_empty() {
+            problems.push(format!("{side}.host is empty"));
+        }
+
+        if self.port == 0 {
+            problems.push(format!("{side}.port must be greater than 0"));
+        }
+
+        // ssh 옵션으로 해석될 수 있는 값은 허용하지 않습니다.
+        for (name, value) in [("host", Some(&self.host)), ("user", self.user.as_ref())] {
+            if value.is_some_and(|value| value.starts_with('-')) {
+                problems.push(format!("{side}.{name} must not start with '-'"));
+            }
+        }
+
+        problems
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Config {
     pub database_pairs: Vec<DatabasePair>,
     pub default_database_pair: Option<DatabasePair>,
     pub current_language: Language,
-    pub ignore_list: Vec<CheckType>,
+    // 모든 데이터베이스 쌍에 적용되는 무시 규칙
+    pub ignore_list: Vec<IgnoreRule>,
+    // CheckType별 심각도. 없는 항목은 CheckType::default_severity를 사용합니다.
+    #[serde(default)]
+    pub severity: HashMap<CheckType, Severity>,
+    // --ci 실행에서 이 심각도 이상의 차이가 있으면 실패로 종료합니다.
+    #[serde(default)]
+    pub fail_on: Severity,
+    // 하나의 base를 여러 target 환경과 비교하는 그룹
+    #[serde(default)]
+    pub comparison_groups: Vec<ComparisonGroup>,
+    // --baseline이 없을 때 사용하는 baseline 파일 경로
+    // 프로젝트 설정 파일에서는 설정 파일이 있는 디렉터리를 기준으로 합니다.
+    #[serde(default)]
+    pub baseline: Option<String>,
+}
+
+impl Config {
+    // 이름으로 데이터베이스 쌍을 찾습니다. database_pairs에 없다면 default_database_pair도 확인합니다.
+    pub fn find_pair(&self, name: &str) -> Option<&DatabasePair> {
+        self.database_pairs
+            .iter()
+            .chain(self.default_database_pair.iter())
+            .find(|pair| pair.name == name)
+    }
+
+    pub fn find_group(&self, name: &str) -> Option<&ComparisonGroup> {
+        self.comparison_groups
+            .iter()
+            .find(|group| group.name == name)
+    }
+
+    // 모든 데이터베이스 쌍과 비교 그룹의 설정 값 문제를 "이름: 문제" 형태로 반환합니다.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = vec![];
+
+        for (i, pair) in self.database_pairs.iter().enumerate() {
+            if self.database_pairs[..i]
+                .iter()
+                .any(|other| other.name == pair.name)
+            {
+                problems.push(format!("{}: duplicated pair name", pair.name));
+            }
+        }
+
+        for pair in self.all_pairs() {
+            for problem in pair.validate() {
+                problems.push(format!("{}: {problem}", pair.name));
+            }
+        }
+
+        for group in &self.comparison_groups {
+            if group.targets.is_empty() {
+                problems.push(format!("{}: comparison group has no targets", group.name));
+            }
+
+            if let Some(problem) = validate_connection(
+                &group.database_type,
+                "base_connection",
+                &group.base_connection,
+            ) {
+                problems.push(format!("{}: {problem}", group.name));
+            }
+
+            for problem in group.base_tls.validate("base_tls", &group.base_connection) {
+                problems.push(format!("{}: {problem}", group.name));
+            }
+
+            if let Some(ssh_tunnel) = &group.base_ssh_tunnel {
+                for problem in
+                    ssh_tunnel
+                        .validate("base_ssh_tunnel")
+                        .into_iter()
+                        .chain(validate_tunnel_tls(
+                            "base_ssh_tunnel",
+                            &group.base_tls,
+                            &group.base_connection,
+                        ))
+                {
+                    problems.push(format!("{}: {problem}", group.name));
+                }
+            }
+
+            for target in &group.targets {
+                if let Some(problem) =
+                    validate_connection(&group.database_type, "connection", &target.connection)
+                {
+                    problems.push(format!("{}.{}: {problem}", group.name, target.name));
+                }
+
+                for problem in target.tls.validate("tls", &target.connection) {
+                    problems.push(format!("{}.{}: {problem}", group.name, target.name));
+                }
+
+                if let Some(ssh_tunnel) = &target.ssh_tunnel {
+                    for problem in
+                        ssh_tunnel
+                            .validate("ssh_tunnel")
+                            .into_iter()
+                            .chain(validate_tunnel_tls(
+                                "ssh_tunnel",
+                                &target.tls,
+                                &target.connection,
+                            ))
+                    {
+                        problems.push(format!("{}.{}: {problem}", group.name, target.name));
+                    }
+                }
+            }
+        }
+
+        problems
+    }
+
+    // database_pairs와 default_database_pair의 모든 데이터베이스 쌍 (이름 중복 제외)
+    pub fn all_pairs(&self) -> Vec<DatabasePair> {
+        let mut pairs = self.database_pairs.clone();
+
+        if let Some(default_pair) = &self.default_database_pair {
+            if !pairs.iter().any(|pair| pair.name == default_pair.name) {
+                pairs.insert(0, default_pair.clone());
+            }
+        }
+
+        pairs
+    }
+
+    // 데이터베이스 쌍을 저장하고 기본 쌍으로 선택합니다.
+    // 이전 기본 쌍이 database_pairs에 없다면, 잃어버리지 않도록 database_pairs에 추가합니다.
+    pub fn select_default_pair(&mut self, database_pair: DatabasePair) {
+        if let Some(previous_pair) = self.default_database_pair.take() {
+            if previous_pair.name != database_pair.name
+                && !self
+                    .database_pairs
+                    .iter()
+                    .any(|pair| pair.name == previous_pair.name)
+            {
+                self.database_pairs.push(previous_pair);
+            }
+        }
+
+        self.upsert_pair(database_pair.clone());
+        self.default_database_pair = Some(database_pair);
+    }
+
+    // 같은 이름의 데이터베이스 쌍이 있다면 교체하고, 없다면 추가합니다.
+    // default_database_pair와 이름이 같다면 함께 갱신합니다.
+    pub fn upsert_pair(&mut self, database_pair: DatabasePair) {
+        match self
+            .database_pairs
+            .iter_mut()
+            .find(|pair| pair.name == database_pair.name)
+        {
+            Some(pair) => *pair = database_pair.clone(),
+            None => self.database_pairs.push(database_pair.clone()),
+        }
+
+        if let Some(default_pair) = &mut self.default_database_pair {
+            if default_pair.name == database_pair.name {
+                *default_pair = database_pair;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ssh_tunnel() -> Option<SshTunnelOptions> {
+        Some(SshTunnelOptions {
+            host: "bastion.internal".to_string(),
+            port: 22,
+            user: None,
+            key_file: None,
+            known_hosts_file: None,
+        })
+    }
+
+    // 인증서 경로는 파일이 있어야 하므로, 빈 파일을 만들어서 사용합니다.
+    fn cert_file(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "magiceye-config-test-{}-{name}",
+            std::process::id()
+        ));
+        std::fs::write(&path, "").unwrap();
+
+        path.to_string_lossy().to_string()
+    }
+
+    fn tunneled_pair(connection: &str, mode: Option<TlsMode>) -> DatabasePair {
+        DatabasePair {
+            base_connection: connection.to_string(),
+            target_connection: "postgres://localhost/dev".to_string(),
+            base_tls: TlsOptions {
+                mode,
+                root_cert: Some(cert_file("ca.pem")),
+                ..TlsOptions::default()
+            },
+            base_ssh_tunnel: ssh_tunnel(),
+            ..DatabasePair::default()
+        }
+    }
+
+    fn has_tunnel_tls_problem(problems: &[String]) -> bool {
+        problems
+            .iter()
+            .any(|problem| problem.contains("cannot be used through an ssh tunnel"))
+    }
+
+    #[test]
+    fn verify_full_through_tunnel_is_rejected() {
+        let pair = tunneled_pair("postgres://db.internal/prod", Some(TlsMode::VerifyFull));
+        assert!(has_tunnel_tls_problem(&pair.validate()));
+
+        // URL의 sslmode도 확인합니다.
+        let pair = tunneled_pair("postgres://db.internal/prod?sslmode=verify-full", None);
+        assert!(has_tunnel_tls_problem(&pair.validate()));
+
+        let pair = DatabasePair {
+            database_type: DatabaseType::Mysql,
+            target_connection: "mysql://localhost/dev".to_string(),
+            ..tunneled_pair("mysql://db.internal/prod?ssl-mode=VERIFY_IDENTITY", None)
+        };
+        assert!(has_tunnel_tls_problem(&pair.validate()));
+    }
+
+    #[test]
+    fn verify_ca_through_tunnel_is_allowed() {
+        let pair = tunneled_pair("postgres://db.internal/prod", Some(TlsMode::VerifyCa));
+        assert_eq!(pair.validate(), Vec::<String>::new());
+
+        // TLS 설정의 mode가 URL의 sslmode보다 우선합니다.
+        let pair = tunneled_pair(
+            "postgres://db.internal/prod?sslmode=verify-full",
+            Some(TlsMode::Require),
+        );
+        assert_eq!(pair.validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn verify_full_without_tunnel_is_allowed() {
+        let pair = DatabasePair {
+            base_ssh_tunnel: None,
+            ..tunneled_pair("postgres://db.internal/prod", Some(TlsMode::VerifyFull))
+        };
+        assert_eq!(pair.validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn verify_full_through_tunnel_in_group_target() {
+        let config = Config {
+            comparison_groups: vec![ComparisonGroup {
+                name: "prod".to_string(),
+                database_type: DatabaseType::Postgres,
+                base_connection: "postgres://db.internal/prod".to_string(),
+                targets: vec![TargetEnvironment {
+                    name: "qa".to_string(),
+                    connection: "postgres://qa.internal/qa?sslmode=verify-full".to_string(),
+                    password_command: None,
+                    tls: TlsOptions::default(),
+                    ssh_tunnel: ssh_tunnel(),
+                }],
+                bidirectional: false,
+                pool_size: 5,
+                session: SessionOptions::default(),
+                ignore_list: vec![],
+                base_password_command: None,
+                base_tls: TlsOptions::default(),
+                base_ssh_tunnel: None,
+            }],
+            ..Config::default()
+        };
+
+        let problems = config.validate();
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("prod.qa: ssh_tunnel: TLS mode verify-full"));
+    }
+
+    #[test]
+    fn tls_mode_names() {
+        for (name, mode) in [
+            ("disable", TlsMode::Disable),
+            ("DISABLED", TlsMode::Disable),
+            ("prefer", TlsMode::Prefer),
+            ("Preferred", TlsMode::Prefer),
+            ("require", TlsMode::Require),
+            ("REQUIRED", TlsMode::Require),
+            ("verify-ca", TlsMode::VerifyCa),
+            ("VERIFY_CA", TlsMode::VerifyCa),
+            ("verify-full", TlsMode::VerifyFull),
+            ("VERIFY_IDENTITY", TlsMode::VerifyFull),
+        ] {
+            assert_eq!(TlsMode::from_str(name).unwrap(), mode, "{name}");
+        }
+
+        assert!(TlsMode::from_str("allow").is_err());
+
+        // 설정 파일에서는 PostgreSQL 이름과 소문자 MySQL 이름을 사용합니다.
+        let tls: TlsOptions = serde_json::from_str(r#"{"mode": "verify-identity"}"#).unwrap();
+        assert_eq!(tls.mode, Some(TlsMode::VerifyFull));
+
+        for mode in TlsMode::list() {
+            assert_eq!(
+                serde_json::to_value(mode).unwrap(),
+                serde_json::Value::String(mode.as_str().to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn verify_mode_needs_root_cert() {
+        let url = "postgres://db.internal/prod";
+
+        for mode in [TlsMode::VerifyCa, TlsMode::VerifyFull] {
+            let tls = TlsOptions {
+                mode: Some(mode),
+                ..TlsOptions::default()
+            };
+
+            assert_eq!(
+                tls.validate("base_tls", url),
+                [format!(
+                    "base_tls: mode {} requires root_cert, the CA certificate that signed the server certificate",
+                    mode.as_str()
+                )]
+            );
+
+            // URL의 인증서도 사용할 수 있습니다.
+            assert!(tls
+                .validate("base_tls", "postgres://db.internal/prod?sslrootcert=ca.pem")
+                .is_empty());
+            assert!(tls
+                .validate("base_tls", "mysql://db.internal/prod?ssl-ca=ca.pem")
+                .is_empty());
+
+            let tls = TlsOptions {
+                root_cert: Some(cert_file("ca.pem")),
+                ..tls
+            };
+            assert!(tls.validate("base_tls", url).is_empty());
+        }
+
+        for mode in [TlsMode::Disable, TlsMode::Prefer, TlsMode::Require] {
+            let tls = TlsOptions {
+                mode: Some(mode),
+                ..TlsOptions::default()
+            };
+
+            assert!(tls.validate("base_tls", url).is_empty());
+        }
+    }
+
+    #[test]
+    fn client_cert_and_key_together() {
+        let url = "postgres://db.internal/prod";
+
+        let tls = TlsOptions {
+            client_cert: Some(cert_file("client.pem")),
+            ..TlsOptions::default()
+        };
+        assert_eq!(
+            tls.validate("target_tls", url),
+            ["target_tls: client_cert and client_key must be set together"]
+        );
+
+        let tls = TlsOptions {
+            client_key: Some(cert_file("client.key")),
+            ..tls
+        };
+        assert!(tls.validate("target_tls", url).is_empty());
+
+        let tls = TlsOptions {
+            root_cert: Some("/nonexistent/ca.pem".to_string()),
+            ..TlsOptions::default()
+        };
+        assert_eq!(
+            tls.validate("target_tls", url),
+            ["target_tls.root_cert: file not found (/nonexistent/ca.pem)"]
+        );
+    }
+
+    #[test]
+    fn severity_policy_is_read_per_check_type() {
+        let config: Config = serde_json::from_value(serde_json::json!({
+            "database_pairs": [],
+            "default_database_pair": null,
+            "current_language": "English",
+            "ignore_list": [],
+            "severity": { "CommentOfColumn": "error", "Table": "warning" },
+            "fail_on": "warning"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            config.severity.get(&CheckType::CommentOfColumn),
+            Some(&Severity::Error)
+        );
+        assert_eq!(
+            config.severity.get(&CheckType::Table),
+            Some(&Severity::Warning)
+        );
+        assert_eq!(config.fail_on, Severity::Warning);
+
+        // 생략하면 error 이상에서 실패합니다.
+        let config: Config = serde_json::from_value(serde_json::json!({
+            "database_pairs": [],
+            "default_database_pair": null,
+            "current_language": "English",
+            "ignore_list": []
+        }))
+        .unwrap();
+
+        assert!(config.severity.is_empty());
+        assert_eq!(config.fail_on, Severity::Error);
+    }
+
+    #[test]
+    fn group_target_pair_uses_target_connection_settings() {
+        let group = ComparisonGroup {
+            name: "release".to_string(),
+            database_type: DatabaseType::Postgres,
+            base_connection: "postgres://localhost/prod".to_string(),
+            targets: vec![TargetEnvironment {
+                name: "qa".to_string(),
+                connection: "postgres://localhost/qa".to_string(),
+                password_command: Some("pass show qa".to_string()),
+                tls: TlsOptions::default(),
+                ssh_tunnel: None,
+            }],
+            bidirectional: true,
+            pool_size: 3,
+            session: SessionOptions::default(),
+            ignore_list: vec![],
+            base_password_command: Some("pass show prod".to_string()),
+            base_tls: TlsOptions::default(),
+            base_ssh_tunnel: ssh_tunnel(),
+        };
+
+        let pair = group.target_pair(&group.targets[0]);
+
+        assert_eq!(pair.name, "release.qa");
+        assert_eq!(pair.base_connection, "postgres://localhost/prod");
+        assert_eq!(pair.target_connection, "postgres://localhost/qa");
+        assert_eq!(
+            pair.base_password_command.as_deref(),
+            Some("pass show prod")
+        );
+        assert_eq!(
+            pair.target_password_command.as_deref(),
+            Some("pass show qa")
+        );
+        assert!(pair.base_ssh_tunnel.is_some());
+        assert!(pair.target_ssh_tunnel.is_none());
+        assert!(pair.bidirectional);
+        assert_eq!(pair.pool_size, 3);
+    }
 }
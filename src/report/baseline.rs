@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use super::ReportSchema;
+
+// 의도된 차이로 확인을 마친 항목의 fingerprint 목록입니다.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Baseline {
+    #[serde(default)]
+    pub acknowledged: Vec<String>,
+}
+
+impl Baseline {
+    pub fn read(path: &str) -> anyhow::Result<Self> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) => {
+                return Err(anyhow::anyhow!(
+                    "failed to read baseline file [{path}], error: {error}"
+                ));
+            }
+        };
+
+        match serde_json::from_str(&text) {
+            Ok(baseline) => Ok(baseline),
+            Err(error) => Err(anyhow::anyhow!(
+                "failed to parse baseline file [{path}], error: {error}"
+            )),
+        }
+    }
+
+    pub fn write(&self, path: &str) -> anyhow::Result<()> {
+        let text = serde_json::to_string_pretty(self)?;
+
+        if let Err(error) = std::fs::write(path, text) {
+            return Err(anyhow::anyhow!(
+                "failed to write baseline file [{path}], error: {error}"
+            ));
+        }
+
+        Ok(())
+    }
+
+    // 보고서의 모든 항목을 확인한 것으로 기록합니다.
+    pub fn from_report(report: &ReportSchema) -> Self {
+        let mut acknowledged: Vec<String> = report
+            .findings()
+            .map(|finding| finding.fingerprint())
+            .collect();
+
+        acknowledged.sort();
+        acknowledged.dedup();
+
+        Self { acknowledged }
+    }
+
+    // baseline에 있는 항목은 acknowledged로 표시하고, 일치하는 항목이 없는 baseline 항목은 stale로 기록합니다.
+    pub fn apply(&self, report: &mut ReportSchema) {
+        let mut matched = vec![false; self.acknowledged.len()];
+
+        for report_table in report.report_table_list.iter_mut() {
+            for finding in report_table.report_list.iter_mut() {
+                let fingerprint = finding.fingerprint();
+
+                if let Some(index) = self.acknowledged.iter().position(|f| f == &fingerprint) {
+                    finding.acknowledged = true;
+                    matched[index] = true;
+                }
+            }
+        }
+
+        report.stale_baseline_entries = self
+            .acknowledged
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(fingerprint, _)| fingerprint.clone())
+            .collect();
+
+        report.update_failure_count();
+    }
+}
@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::{markdown, matrix::MatrixReport, ReportSchema};
+
+// 보고서 파일의 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    // PR이나 위키에 붙여넣을 수 있는 형식
+    Markdown,
+}
+
+impl ReportFormat {
+    // 파일 이름 템플릿의 {ext}에 들어가는 확장자
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+// 명령행 인자로 받을 때 사용합니다. (예: --format json)
+impl FromStr for ReportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(anyhow::anyhow!(
+                "unknown report format: {value} (expected json or markdown)"
+            )),
+        }
+    }
+}
+
+// 보고서를 선택한 형식의 문자열로 만듭니다.
+pub trait RenderReport {
+    fn render(&self, format: ReportFormat) -> anyhow::Result<String>;
+}
+
+impl RenderReport for ReportSchema {
+    fn render(&self, format: ReportFormat) -> anyhow::Result<String> {
+        match format {
+            ReportFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            ReportFormat::Markdown => Ok(markdown::render(self)),
+        }
+    }
+}
+
+impl RenderReport for MatrixReport {
+    fn render(&self, format: ReportFormat) -> anyhow::Result<String> {
+        match format {
+            ReportFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            ReportFormat::Markdown => Ok(markdown::render_matrix(self)),
+        }
+    }
+}
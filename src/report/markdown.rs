@@ -0,0 +1,489 @@
+use std::fmt::Write;
+
+use super::{
+    matrix::MatrixReport, serde_name, Finding, FindingKind, ReportSchema, ReportTable, Severity,
+};
+
+const SEVERITIES: [Severity; 3] = [Severity::Error, Severity::Warning, Severity::Info];
+
+const FINDING_KINDS: [FindingKind; 5] = [
+    FindingKind::Missing,
+    FindingKind::Different,
+    FindingKind::Extra,
+    FindingKind::Renamed,
+    FindingKind::PossiblyRenamed,
+];
+
+// PR이나 위키에 붙여넣을 수 있는 Markdown 보고서를 만듭니다.
+// 1. 결과와 요약 표 (종류별, 테이블별 항목 수)
+// 2. 테이블마다 섹션. 값이 없는 항목(missing, extra)은 목록으로, 값이 다른 항목은 base/target 비교 표로 보여줍니다.
+// 3. 경고, fingerprint 등 자세한 내용은 접어둡니다.
+pub fn render(report: &ReportSchema) -> String {
+    let mut markdown = String::new();
+
+    _ = writeln!(markdown, "# Schema Diff Report");
+    _ = writeln!(markdown);
+    _ = writeln!(markdown, "{}", result_line(report));
+    _ = writeln!(markdown);
+
+    write_summary(&mut markdown, report);
+
+    for report_table in &report.report_table_list {
+        write_table_section(&mut markdown, report_table);
+    }
+
+    if !report.warnings.is_empty() {
+        _ = writeln!(markdown, "## Warnings");
+        _ = writeln!(markdown);
+
+        for warning in &report.warnings {
+            _ = writeln!(markdown, "- {}", escape_text(warning));
+        }
+
+        _ = writeln!(markdown);
+    }
+
+    if !report.stale_baseline_entries.is_empty() {
+        write_details(
+            &mut markdown,
+            &format!(
+                "Stale baseline entries ({})",
+                report.stale_baseline_entries.len()
+            ),
+            &report.stale_baseline_entries,
+        );
+    }
+
+    markdown
+}
+
+fn result_line(report: &ReportSchema) -> String {
+    if report.failure_count > 0 {
+        format!(
+            "**Result: FAILED** ({} findings at or above {})",
+            report.failure_count,
+            serde_name(&report.fail_on)
+        )
+    } else {
+        "**Result: PASSED**".to_string()
+    }
+}
+
+fn write_summary(markdown: &mut String, report: &ReportSchema) {
+    _ = writeln!(markdown, "## Summary");
+    _ = writeln!(markdown);
+    _ = writeln!(
+        markdown,
+        "{} findings, acknowledged: {}, suppressed: {}, stale baseline entries: {}",
+        report.findings().count(),
+        report
+            .findings()
+            .filter(|finding| finding.acknowledged)
+            .count(),
+        report.suppressed_count,
+        report.stale_baseline_entries.len()
+    );
+    _ = writeln!(markdown);
+
+    if report.findings().next().is_none() {
+        return;
+    }
+
+    // 종류별 항목 수
+    _ = writeln!(markdown, "| Category | Error | Warning | Info | Total |");
+    _ = writeln!(markdown, "| --- | ---: | ---: | ---: | ---: |");
+
+    for finding_kind in FINDING_KINDS {
+        let findings: Vec<&Finding> = report
+            .findings()
+            .filter(|finding| finding.finding_kind == finding_kind)
+            .collect();
+
+        if findings.is_empty() {
+            continue;
+        }
+
+        _ = writeln!(
+            markdown,
+            "| {} |{}",
+            serde_name(&finding_kind),
+            count_cells(&findings)
+        );
+    }
+
+    _ = writeln!(markdown);
+
+    // 테이블별 항목 수
+    _ = writeln!(markdown, "| Table | Error | Warning | Info | Total |");
+    _ = writeln!(markdown, "| --- | ---: | ---: | ---: | ---: |");
+
+    for report_table in &report.report_table_list {
+        let findings: Vec<&Finding> = report_table.report_list.iter().collect();
+
+        if findings.is_empty() {
+            continue;
+        }
+
+        _ = writeln!(
+            markdown,
+            "| {} |{}",
+            code(&report_table.table_name),
+            count_cells(&findings)
+        );
+    }
+
+    _ = writeln!(markdown);
+}
+
+// 심각도별 항목 수와 합계 칸
+fn count_cells(findings: &[&Finding]) -> String {
+    let mut cells = String::new();
+
+    for severity in SEVERITIES {
+        let count = findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .count();
+
+        _ = write!(cells, " {count} |");
+    }
+
+    _ = write!(cells, " {} |", findings.len());
+
+    cells
+}
+
+fn write_table_section(markdown: &mut String, report_table: &ReportTable) {
+    if report_table.report_list.is_empty() {
+        return;
+    }
+
+    _ = writeln!(markdown, "## {}", code(&report_table.table_name));
+    _ = writeln!(markdown);
+
+    // A. 값이 없는 항목은 목록으로 보여줍니다.
+    let listed: Vec<&Finding> = report_table
+        .report_list
+        .iter()
+        .filter(|finding| finding.property.is_none())
+        .collect();
+
+    for finding in &listed {
+        _ = writeln!(
+            markdown,
+            "- **{}** {} {} {}{}",
+            serde_name(&finding.severity),
+            serde_name(&finding.finding_kind),
+            finding.object_kind.label(),
+            code(&finding.object_path),
+            acknowledged_mark(finding)
+        );
+    }
+
+    if !listed.is_empty() {
+        _ = writeln!(markdown);
+    }
+
+    // B. 값이 다른 항목은 base와 target을 비교하는 표로 보여줍니다.
+    let compared: Vec<&Finding> = report_table
+        .report_list
+        .iter()
+        .filter(|finding| finding.property.is_some())
+        .collect();
+
+    if !compared.is_empty() {
+        _ = writeln!(markdown, "| Severity | Object | Property | Base | Target |");
+        _ = writeln!(markdown, "| --- | --- | --- | --- | --- |");
+
+        for finding in &compared {
+            _ = writeln!(
+                markdown,
+                "| {}{} | {} {} | {} | {} | {} |",
+                serde_name(&finding.severity),
+                acknowledged_mark(finding),
+                finding.object_kind.label(),
+                code(&finding.object_path),
+                finding
+                    .property
+                    .map(|property| serde_name(&property))
+                    .unwrap_or_default(),
+                code(finding.base_value.as_deref().unwrap_or_default()),
+                code(finding.target_value.as_deref().unwrap_or_default())
+            );
+        }
+
+        _ = writeln!(markdown);
+    }
+
+    // C. 설정된 언어로 렌더링된 문장과 fingerprint는 접어둡니다.
+    let details: Vec<String> = report_table
+        .report_list
+        .iter()
+        .map(|finding| format!("{}\n  {}", finding.message, finding.fingerprint()))
+        .collect();
+
+    write_details(markdown, "Details", &details);
+}
+
+// 비교 그룹의 매트릭스 보고서. 객체와 속성마다 한 줄, 환경마다 한 칸입니다.
+pub fn render_matrix(report: &MatrixReport) -> String {
+    let mut markdown = String::new();
+
+    _ = writeln!(markdown, "# Schema Diff Matrix: {}", report.group_name);
+    _ = writeln!(markdown);
+
+    let result_line = if !report.errors.is_empty() {
+        "**Result: ERROR**".to_string()
+    } else if report.failure_count > 0 {
+        format!(
+            "**Result: FAILED** ({} findings at or above {})",
+            report.failure_count,
+            serde_name(&report.fail_on)
+        )
+    } else {
+        "**Result: PASSED**".to_string()
+    };
+
+    _ = writeln!(markdown, "{result_line}");
+    _ = writeln!(markdown);
+
+    if !report.rows.is_empty() {
+        _ = write!(markdown, "| Object | Property | Base |");
+
+        for environment in &report.environments {
+            _ = write!(markdown, " {} |", escape_text(environment));
+        }
+
+        _ = writeln!(markdown);
+        _ = write!(markdown, "| --- | --- | --- |");
+
+        for _ in &report.environments {
+            _ = write!(markdown, " --- |");
+        }
+
+        _ = writeln!(markdown);
+
+        for row in &report.rows {
+            _ = write!(
+                markdown,
+                "| {} {} | {} | {} |",
+                row.object_kind.label(),
+                code(&row.object_path),
+                row.property
+                    .map(|property| serde_name(&property))
+                    .unwrap_or_default(),
+                code(row.base_value.as_deref().unwrap_or_default())
+            );
+
+            for cell in &row.cells {
+                let text = match (cell.finding_kind, &cell.target_value) {
+                    (None, _) => "ok".to_string(),
+                    (Some(finding_kind), Some(target_value)) => {
+                        format!("{} {}", serde_name(&finding_kind), code(target_value))
+                    }
+                    (Some(finding_kind), None) => serde_name(&finding_kind),
+                };
+
+                let mark = if cell.acknowledged {
+                    " (acknowledged)"
+                } else {
+                    ""
+                };
+
+                _ = write!(markdown, " {text}{mark} |");
+            }
+
+            _ = writeln!(markdown);
+        }
+
+        _ = writeln!(markdown);
+    }
+
+    for (title, lines) in [("Errors", &report.errors), ("Warnings", &report.warnings)] {
+        if lines.is_empty() {
+            continue;
+        }
+
+        _ = writeln!(markdown, "## {title}");
+        _ = writeln!(markdown);
+
+        for line in lines {
+            _ = writeln!(markdown, "- {}", escape_text(line));
+        }
+
+        _ = writeln!(markdown);
+    }
+
+    markdown
+}
+
+fn write_details(markdown: &mut String, summary: &str, lines: &[String]) {
+    _ = writeln!(markdown, "<details>");
+    _ = writeln!(markdown, "<summary>{}</summary>", escape_html(summary));
+    _ = writeln!(markdown);
+    _ = writeln!(markdown, "```text");
+
+    for line in lines {
+        _ = writeln!(markdown, "{line}");
+    }
+
+    _ = writeln!(markdown, "```");
+    _ = writeln!(markdown);
+    _ = writeln!(markdown, "</details>");
+    _ = writeln!(markdown);
+}
+
+fn acknowledged_mark(finding: &Finding) -> &'static str {
+    if finding.acknowledged {
+        " (acknowledged)"
+    } else {
+        ""
+    }
+}
+
+// 표 안에서도 깨지지 않도록, 값을 인라인 코드로 감쌉니다.
+// 값에 백틱이 있다면 더 긴 백틱으로 감싸고, 빈 값은 그대로 둡니다.
+fn code(value: &str) -> String {
+    if value.is_empty() {
+        return String::new();
+    }
+
+    let value = value.replace('|', "\\|").replace('\n', " ");
+
+    let mut fence = "`".to_string();
+
+    while value.contains(fence.as_str()) {
+        fence.push('`');
+    }
+
+    if value.starts_with('`') || value.ends_with('`') {
+        format!("{fence} {value} {fence}")
+    } else {
+        format!("{fence}{value}{fence}")
+    }
+}
+
+// 목록과 표에 들어가는 일반 문장
+fn escape_text(value: &str) -> String {
+    escape_html(value).replace('|', "\\|").replace('\n', " ")
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{report::ObjectKind, sql::Property};
+
+    fn report(findings: Vec<Finding>) -> ReportSchema {
+        let mut report = ReportSchema {
+            report_table_list: vec![ReportTable {
+                table_name: "users".to_string(),
+                report_list: findings,
+            }],
+            warnings: vec![],
+            suppressed_count: 0,
+            stale_baseline_entries: vec![],
+            fail_on: Severity::Error,
+            failure_count: 0,
+        };
+        report.update_failure_count();
+
+        report
+    }
+
+    #[test]
+    fn findings_are_listed_or_compared_in_a_table() {
+        let mut comment = Finding::different(
+            ObjectKind::Column,
+            "users.email".to_string(),
+            Property::Comment,
+            "login | email".to_string(),
+            String::new(),
+        );
+        comment.severity = Severity::Info;
+
+        let markdown = render(&report(vec![
+            Finding::missing(ObjectKind::Column, "users.name".to_string()),
+            comment,
+        ]));
+
+        assert!(markdown.contains("**Result: FAILED** (1 findings at or above error)"));
+        assert!(markdown.contains("| missing | 1 | 0 | 0 | 1 |"));
+        assert!(markdown.contains("| different | 0 | 0 | 1 | 1 |"));
+        assert!(markdown.contains("| `users` | 1 | 0 | 1 | 2 |"));
+        assert!(markdown.contains("- **error** missing Column `users.name`"));
+        // 표 안의 |는 이스케이프하고, 빈 값은 그대로 둡니다.
+        assert!(
+            markdown.contains("| info | Column `users.email` | comment | `login \\| email` |  |")
+        );
+        assert!(markdown.contains("<summary>Details</summary>"));
+    }
+
+    #[test]
+    fn empty_report_passes_without_tables() {
+        let markdown = render(&report(vec![]));
+
+        assert!(markdown.contains("**Result: PASSED**"));
+        assert!(markdown.contains("0 findings, acknowledged: 0, suppressed: 0"));
+        assert!(!markdown.contains("| Category |"));
+        assert!(!markdown.contains("## `users`"));
+    }
+
+    #[test]
+    fn acknowledged_findings_are_marked() {
+        let mut finding = Finding::missing(ObjectKind::Table, "users".to_string());
+        finding.acknowledged = true;
+
+        let markdown = render(&report(vec![finding]));
+
+        assert!(markdown.contains("**Result: PASSED**"));
+        assert!(markdown.contains("- **error** missing Table `users` (acknowledged)"));
+    }
+
+    #[test]
+    fn values_with_backticks_use_a_longer_fence() {
+        assert_eq!(code(""), "");
+        assert_eq!(code("varchar(255)"), "`varchar(255)`");
+        assert_eq!(code("a`b"), "``a`b``");
+        assert_eq!(code("`quoted`"), "`` `quoted` ``");
+        assert_eq!(code("a|b\nc"), "`a\\|b c`");
+        assert_eq!(escape_text("<script> & |"), "&lt;script&gt; &amp; \\|");
+    }
+
+    #[test]
+    fn matrix_has_a_column_per_environment() {
+        let report = MatrixReport::new(
+            "release".to_string(),
+            vec![
+                ("dev".to_string(), report(vec![])),
+                (
+                    "qa".to_string(),
+                    report(vec![Finding::different(
+                        ObjectKind::Column,
+                        "users.email".to_string(),
+                        Property::DataType,
+                        "varchar(255)".to_string(),
+                        "text".to_string(),
+                    )]),
+                ),
+            ],
+            Severity::Error,
+        );
+
+        let markdown = render_matrix(&report);
+
+        assert!(markdown.starts_with("# Schema Diff Matrix: release\n"));
+        assert!(markdown.contains("**Result: FAILED** (1 findings at or above error)"));
+        assert!(markdown.contains("| Object | Property | Base | dev | qa |"));
+        assert!(markdown.contains(
+            "| Column `users.email` | data_type | `varchar(255)` | ok | different `text` |"
+        ));
+    }
+}
@@ -0,0 +1,267 @@
+use serde::{Deserialize, Serialize};
+
+use crate::sql::Property;
+
+use super::{Finding, FindingKind, ObjectKind, ReportSchema, Severity};
+
+// 하나의 base를 여러 target 환경과 비교한 결과입니다.
+// 객체와 속성마다, 각 환경이 base와 같은지 다른지를 한 줄로 보여줍니다.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MatrixReport {
+    pub group_name: String,
+    // 비교에 성공한 환경 이름. 각 row의 cells와 순서가 같습니다.
+    pub environments: Vec<String>,
+    pub rows: Vec<MatrixRow>,
+    // 환경 이름이 앞에 붙은 경고
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    // 연결 실패 등으로 비교하지 못한 환경과 그 이유
+    #[serde(default)]
+    pub errors: Vec<String>,
+    #[serde(default)]
+    pub fail_on: Severity,
+    // 모든 환경의 failure_count 합
+    #[serde(default)]
+    pub failure_count: usize,
+}
+
+// 하나의 객체 속성에 대한 환경별 결과
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MatrixRow {
+    pub object_kind: ObjectKind,
+    pub object_path: String,
+    pub property: Option<Property>,
+    pub base_value: Option<String>,
+    pub cells: Vec<MatrixCell>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MatrixCell {
+    pub environment: String,
+    // None이면 base와 같습니다.
+    pub finding_kind: Option<FindingKind>,
+    pub severity: Option<Severity>,
+    pub target_value: Option<String>,
+    #[serde(default)]
+    pub acknowledged: bool,
+}
+
+impl MatrixReport {
+    // 환경별 보고서를 객체와 속성 기준으로 모읍니다.
+    pub fn new(
+        group_name: String,
+        reports: Vec<(String, ReportSchema)>,
+        fail_on: Severity,
+    ) -> Self {
+        let mut rows: Vec<MatrixRow> = vec![];
+
+        for (_, report) in &reports {
+            for finding in report.findings() {
+                let exists = rows.iter().any(|row| {
+                    row.object_kind == finding.object_kind
+                        && row.object_path == finding.object_path
+                        && row.property == finding.property
+                });
+
+                if !exists {
+                    rows.push(MatrixRow {
+                        object_kind: finding.object_kind,
+                        object_path: finding.object_path.clone(),
+                        property: finding.property,
+                        base_value: finding.base_value.clone(),
+                        cells: vec![],
+                    });
+                }
+            }
+        }
+
+        rows.sort_by(|a, b| a.object_path.cmp(&b.object_path));
+
+        for row in rows.iter_mut() {
+            row.cells = reports
+                .iter()
+                .map(|(environment, report)| MatrixCell::new(environment, report, row))
+                .collect();
+        }
+
+        let mut warnings = vec![];
+
+        for (environment, report) in &reports {
+            warnings.extend(
+                report
+                    .warnings
+                    .iter()
+                    .map(|warning| format!("[{environment}] {warning}")),
+            );
+        }
+
+        Self {
+            group_name,
+            environments: reports
+                .iter()
+                .map(|(environment, _)| environment.clone())
+                .collect(),
+            rows,
+            warnings,
+            errors: vec![],
+            fail_on,
+            failure_count: reports.iter().map(|(_, report)| report.failure_count).sum(),
+        }
+    }
+}
+
+impl MatrixCell {
+    fn new(environment: &str, report: &ReportSchema, row: &MatrixRow) -> Self {
+        let finding = report
+            .findings()
+            .find(|finding| {
+                finding.object_kind == row.object_kind
+                    && finding.object_path == row.object_path
+                    && finding.property == row.property
+            })
+            // 속성이 같아 보이더라도, 이 환경에 객체나 테이블 자체가 없다면 없는 것으로 표시합니다.
+            .or_else(|| find_missing_parent(report, &row.object_path));
+
+        match finding {
+            Some(finding) => Self {
+                environment: environment.to_string(),
+                finding_kind: Some(finding.finding_kind),
+                severity: Some(finding.severity),
+                target_value: finding.target_value.clone(),
+                acknowledged: finding.acknowledged,
+            },
+            None => Self {
+                environment: environment.to_string(),
+                finding_kind: None,
+                severity: None,
+                target_value: None,
+                acknowledged: false,
+            },
+        }
+    }
+
+    pub fn is_match(&self) -> bool {
+        self.finding_kind.is_none()
+    }
+}
+
+fn find_missing_parent<'a>(report: &'a ReportSchema, object_path: &str) -> Option<&'a Finding> {
+    let table_name = object_path.split('.').next().unwrap_or_default();
+
+    report.findings().find(|finding| {
+        finding.finding_kind == FindingKind::Missing
+            && finding.property.is_none()
+            && (finding.object_path == object_path || finding.object_path == table_name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::report::ReportTable;
+
+    fn report(findings: Vec<Finding>, warnings: Vec<String>) -> ReportSchema {
+        let mut report = ReportSchema {
+            report_table_list: vec![ReportTable {
+                table_name: "users".to_string(),
+                report_list: findings,
+            }],
+            warnings,
+            suppressed_count: 0,
+            stale_baseline_entries: vec![],
+            fail_on: Severity::Error,
+            failure_count: 0,
+        };
+        report.update_failure_count();
+
+        report
+    }
+
+    fn different_type(target_value: &str) -> Finding {
+        Finding::different(
+            ObjectKind::Column,
+            "users.email".to_string(),
+            Property::DataType,
+            "varchar(255)".to_string(),
+            target_value.to_string(),
+        )
+    }
+
+    #[test]
+    fn rows_are_merged_across_environments() {
+        let report = MatrixReport::new(
+            "release".to_string(),
+            vec![
+                ("dev".to_string(), report(vec![], vec![])),
+                (
+                    "qa".to_string(),
+                    report(vec![different_type("varchar(100)")], vec![]),
+                ),
+                (
+                    "stage".to_string(),
+                    report(vec![different_type("text")], vec![]),
+                ),
+            ],
+            Severity::Error,
+        );
+
+        assert_eq!(report.environments, ["dev", "qa", "stage"]);
+        assert_eq!(report.rows.len(), 1);
+        assert_eq!(report.failure_count, 2);
+
+        let row = &report.rows[0];
+        assert_eq!(row.object_path, "users.email");
+        assert_eq!(row.base_value.as_deref(), Some("varchar(255)"));
+
+        let cells: Vec<(&str, bool, Option<&str>)> = row
+            .cells
+            .iter()
+            .map(|cell| {
+                (
+                    cell.environment.as_str(),
+                    cell.is_match(),
+                    cell.target_value.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            cells,
+            [
+                ("dev", true, None),
+                ("qa", false, Some("varchar(100)")),
+                ("stage", false, Some("text")),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_table_marks_its_rows_missing() {
+        let report = MatrixReport::new(
+            "release".to_string(),
+            vec![
+                (
+                    "qa".to_string(),
+                    report(vec![different_type("varchar(100)")], vec![]),
+                ),
+                (
+                    "stage".to_string(),
+                    report(
+                        vec![Finding::missing(ObjectKind::Table, "users".to_string())],
+                        vec!["schema changed during the run".to_string()],
+                    ),
+                ),
+            ],
+            Severity::Error,
+        );
+
+        let row = report
+            .rows
+            .iter()
+            .find(|row| row.object_path == "users.email")
+            .unwrap();
+        assert_eq!(row.cells[1].finding_kind, Some(FindingKind::Missing));
+
+        assert_eq!(report.warnings, ["[stage] schema changed during the run"]);
+    }
+}
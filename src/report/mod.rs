@@ -0,0 +1,331 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{CheckType, Language},
+    sql::Property,
+};
+
+pub use crate::config::Severity;
+
+pub mod baseline;
+pub mod format;
+pub mod markdown;
+pub mod matrix;
+
+// 차이가 발견된 객체의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectKind {
+    Table,
+    Column,
+    Index,
+    ForeignKey,
+}
+
+impl ObjectKind {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Table => "Table",
+            Self::Column => "Column",
+            Self::Index => "Index",
+            Self::ForeignKey => "Foreign Key",
+        }
+    }
+}
+
+// 차이의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    // base에는 있지만 target에는 없음
+    Missing,
+    // 양쪽에 모두 있지만 속성이 다름
+    Different,
+    // target에만 있음
+    Extra,
+    // 정의는 같지만 이름만 다름
+    Renamed,
+    // 짝을 찾지 못한 객체 중, 이름이 바뀐 것으로 추정됨
+    PossiblyRenamed,
+}
+
+// 비교 결과 하나하나의 항목입니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Finding {
+    pub finding_kind: FindingKind,
+    pub severity: Severity,
+    pub object_kind: ObjectKind,
+    // "table", "table.column", "table.index" 형태
+    pub object_path: String,
+    pub property: Option<Property>,
+    pub base_value: Option<String>,
+    pub target_value: Option<String>,
+    // 추정으로 찾은 항목의 신뢰도 (0.0 ~ 1.0)
+    pub confidence: Option<f64>,
+    // 설정된 언어로 렌더링된 문장
+    pub message: String,
+    // baseline 파일에 기록된, 이미 확인한 차이인지 여부
+    #[serde(default)]
+    pub acknowledged: bool,
+}
+
+impl Finding {
+    pub fn missing(object_kind: ObjectKind, object_path: String) -> Self {
+        Self {
+            finding_kind: FindingKind::Missing,
+            severity: Severity::Error,
+            object_kind,
+            object_path,
+            property: None,
+            base_value: None,
+            target_value: None,
+            confidence: None,
+            message: String::new(),
+            acknowledged: false,
+        }
+    }
+
+    pub fn extra(object_kind: ObjectKind, object_path: String) -> Self {
+        Self {
+            finding_kind: FindingKind::Extra,
+            ..Self::missing(object_kind, object_path)
+        }
+    }
+
+    pub fn different(
+        object_kind: ObjectKind,
+        object_path: String,
+        property: Property,
+        base_value: String,
+        target_value: String,
+    ) -> Self {
+        Self {
+            finding_kind: FindingKind::Different,
+            severity: Severity::Error,
+            object_kind,
+            object_path,
+            property: Some(property),
+            base_value: Some(base_value),
+            target_value: Some(target_value),
+            confidence: None,
+            message: String::new(),
+            acknowledged: false,
+        }
+    }
+
+    // 자동 생성된 이름처럼 이름만 다른 경우이므로, 심각도가 낮습니다.
+    pub fn renamed(
+        object_kind: ObjectKind,
+        object_path: String,
+        base_name: String,
+        target_name: String,
+    ) -> Self {
+        Self {
+            finding_kind: FindingKind::Renamed,
+            severity: Severity::Info,
+            ..Self::different(
+                object_kind,
+                object_path,
+                Property::Name,
+                base_name,
+                target_name,
+            )
+        }
+    }
+
+    // 이름이 바뀐 것으로 추정되는 경우입니다. 추정이므로 신뢰도를 함께 기록합니다.
+    pub fn possibly_renamed(
+        object_kind: ObjectKind,
+        object_path: String,
+        base_name: String,
+        target_name: String,
+        confidence: f64,
+    ) -> Self {
+        Self {
+            finding_kind: FindingKind::PossiblyRenamed,
+            severity: Severity::Warning,
+            confidence: Some((confidence * 100.0).round() / 100.0),
+            ..Self::different(
+                object_kind,
+                object_path,
+                Property::Name,
+                base_name,
+                target_name,
+            )
+        }
+    }
+
+    // 항목을 식별하는 사람이 읽을 수 있는 문자열입니다. 언어 설정이나 실행 시점과 무관합니다.
+    // 예: "different column tags.name data_type: varchar(255) -> varchar(155)"
+    pub fn fingerprint(&self) -> String {
+        let mut fingerprint = format!(
+            "{} {} {}",
+            serde_name(&self.finding_kind),
+            serde_name(&self.object_kind),
+            self.object_path
+        );
+
+        if let Some(property) = self.property {
+            fingerprint.push_str(&format!(
+                " {}: {} -> {}",
+                serde_name(&property),
+                self.base_value.as_deref().unwrap_or_default(),
+                self.target_value.as_deref().unwrap_or_default()
+            ));
+        }
+
+        fingerprint
+    }
+
+    // 무시 규칙 등에서 사용하는 항목의 분류
+    pub fn check_type(&self) -> CheckType {
+        match (self.object_kind, self.property) {
+            (ObjectKind::Table, Some(Property::Comment)) => CheckType::CommentOfTable,
+            (ObjectKind::Table, _) => CheckType::Table,
+            (ObjectKind::Column, Some(Property::DataType)) => CheckType::TypeOfColumn,
+            (ObjectKind::Column, Some(Property::Comment)) => CheckType::CommentOfColumn,
+            (ObjectKind::Column, Some(Property::Nullable)) => CheckType::NullableOfColumn,
+            (ObjectKind::Column, Some(Property::Default)) => CheckType::DefaultOfColumn,
+            (ObjectKind::Column, Some(Property::AutoIncrement)) => CheckType::AutoIncrementOfColumn,
+            (ObjectKind::Column, _) => CheckType::ColumnOfTable,
+            (ObjectKind::Index, _) => CheckType::IndexOfTable,
+            (ObjectKind::ForeignKey, _) => CheckType::ForeignKeyOfTable,
+        }
+    }
+
+    // 설정된 언어로 문장을 생성합니다.
+    pub fn render(&self, language: &Language) -> String {
+        let label = self.object_kind.label();
+        let path = &self.object_path;
+
+        match self.finding_kind {
+            FindingKind::Missing => match language {
+                Language::Korean => format!(
+                    "{label}: {path}가 base 데이터베이스에는 있지만, target 데이터베이스에는 없습니다."
+                ),
+                Language::English => format!(
+                    "{label}: {path} exists in the base database, but not in the target database."
+                ),
+            },
+            FindingKind::Extra => match language {
+                Language::Korean => format!(
+                    "{label}: {path}가 target 데이터베이스에는 있지만, base 데이터베이스에는 없습니다."
+                ),
+                Language::English => format!(
+                    "{label}: {path} exists in the target database, but not in the base database."
+                ),
+            },
+            FindingKind::PossiblyRenamed => {
+                let target_name = self.target_value.as_deref().unwrap_or_default();
+                let confidence = self.confidence.unwrap_or_default();
+
+                match language {
+                    Language::Korean => format!(
+                        "{label}: {path}가 target 데이터베이스에서 {target_name}(으)로 이름이 바뀐 것으로 보입니다. (신뢰도: {confidence:.2})"
+                    ),
+                    Language::English => format!(
+                        "{label}: {path} was possibly renamed to {target_name} in the target database. (confidence: {confidence:.2})"
+                    ),
+                }
+            }
+            FindingKind::Different | FindingKind::Renamed => {
+                let base_value = self.base_value.as_deref().unwrap_or_default();
+                let target_value = self.target_value.as_deref().unwrap_or_default();
+
+                let description = match (language, self.property) {
+                    (Language::Korean, Some(Property::DataType)) => "의 데이터 타입이 다릅니다.",
+                    (Language::Korean, Some(Property::Comment)) => "의 코멘트가 다릅니다.",
+                    (Language::Korean, Some(Property::Nullable)) => "의 NULLABLE이 다릅니다.",
+                    (Language::Korean, Some(Property::Default)) => "의 DEFAULT 값이 다릅니다.",
+                    (Language::Korean, Some(Property::AutoIncrement)) => {
+                        "의 AUTO_INCREMENT 여부가 다릅니다."
+                    }
+                    (Language::Korean, Some(Property::Columns)) => {
+                        "의 컬럼이 다릅니다. 순서까지 확인해주세요."
+                    }
+                    (Language::Korean, Some(Property::Predicate)) => "의 조건이 다릅니다.",
+                    (Language::Korean, Some(Property::Unique)) => "의 UNIQUE 여부가 다릅니다.",
+                    (Language::Korean, Some(Property::References)) => "의 참조 컬럼이 다릅니다.",
+                    (Language::Korean, Some(Property::Name)) => {
+                        "의 정의는 같지만 이름이 다릅니다."
+                    }
+                    (Language::Korean, None) => "가 다릅니다.",
+                    (Language::English, Some(Property::DataType)) => " has different data type.",
+                    (Language::English, Some(Property::Comment)) => " has different comment.",
+                    (Language::English, Some(Property::Nullable)) => " has different nullable.",
+                    (Language::English, Some(Property::Default)) => {
+                        " has different default value."
+                    }
+                    (Language::English, Some(Property::AutoIncrement)) => {
+                        " has different AUTO_INCREMENT."
+                    }
+                    (Language::English, Some(Property::Columns)) => {
+                        " has different columns. Please check the order."
+                    }
+                    (Language::English, Some(Property::Predicate)) => {
+                        " has different predicate."
+                    }
+                    (Language::English, Some(Property::Unique)) => " has different uniqueness.",
+                    (Language::English, Some(Property::References)) => {
+                        " references different column."
+                    }
+                    (Language::English, Some(Property::Name)) => {
+                        " has the same definition, but a different name."
+                    }
+                    (Language::English, None) => " is different.",
+                };
+
+                format!("{label}: {path}{description} => {base_value} != {target_value}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReportTable {
+    pub table_name: String,
+    pub report_list: Vec<Finding>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReportSchema {
+    pub report_table_list: Vec<ReportTable>,
+    // 조회 중 스키마 변경 등, 보고서 전체의 신뢰도에 관한 경고
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    // 무시 규칙에 의해 제외된 항목 수
+    #[serde(default)]
+    pub suppressed_count: usize,
+    // baseline 파일에는 있지만, 더 이상 일치하는 항목이 없는 fingerprint
+    #[serde(default)]
+    pub stale_baseline_entries: Vec<String>,
+    // 이 심각도 이상인 항목을 실패로 봅니다.
+    #[serde(default)]
+    pub fail_on: Severity,
+    // 확인하지 않은(acknowledged가 아닌) 항목 중, fail_on 이상인 항목 수
+    #[serde(default)]
+    pub failure_count: usize,
+}
+
+impl ReportSchema {
+    pub fn findings(&self) -> impl Iterator<Item = &Finding> {
+        self.report_table_list
+            .iter()
+            .flat_map(|report_table| report_table.report_list.iter())
+    }
+
+    pub fn update_failure_count(&mut self) {
+        self.failure_count = self
+            .findings()
+            .filter(|finding| !finding.acknowledged && finding.severity >= self.fail_on)
+            .count();
+    }
+}
+
+// serde로 직렬화했을 때의 이름 (snake_case)
+pub fn serde_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,1727 @@
+use std::collections::HashMap;
+
+use crate::config::DatabaseType;
+
+use super::{Column, ForeignKey, Index, SelectColumn, Table};
+
+// pg_dump --schema-only, mysqldump --no-data 등으로 생성된 DDL 파일을 읽어서 테이블 목록으로 변환합니다.
+pub fn read_ddl_file(database_type: &DatabaseType, path: &str) -> anyhow::Result<Vec<Table>> {
+    let ddl = match std::fs::read_to_string(path) {
+        Ok(ddl) => ddl,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "failed to read ddl file [{path}], error: {error}"
+            ));
+        }
+    };
+
+    parse_ddl(database_type, &ddl)
+}
+
+// DDL 문자열을 파싱합니다.
+// 실제 데이터베이스에서 조회한 것과 같은 형태가 되도록 타입/기본값/인덱스 이름 등을 정규화합니다.
+// 인식하지 못하는 구문(SET, CREATE FUNCTION 등)은 무시합니다.
+pub fn parse_ddl(database_type: &DatabaseType, ddl: &str) -> anyhow::Result<Vec<Table>> {
+    let tokens = tokenize(database_type, ddl)?;
+
+    let mut parser = Parser {
+        database_type: database_type.clone(),
+        source: ddl,
+        tables: vec![],
+        table_index: HashMap::new(),
+    };
+
+    for statement in tokens.split(|token| token.is_symbol(';')) {
+        if statement.is_empty() {
+            continue;
+        }
+
+        parser.statement(statement);
+    }
+
+    if parser.database_type == DatabaseType::Mysql {
+        parser.add_implicit_foreign_key_indexes();
+    }
+
+    Ok(parser.tables)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TokenKind {
+    Word,
+    QuotedIdentifier,
+    String,
+    Number,
+    Symbol,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    // 따옴표가 벗겨진 값
+    value: String,
+    start: usize,
+    end: usize,
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.value.eq_ignore_ascii_case(keyword)
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.kind == TokenKind::Symbol && self.value.starts_with(symbol)
+    }
+
+    fn is_identifier(&self) -> bool {
+        matches!(self.kind, TokenKind::Word | TokenKind::QuotedIdentifier)
+    }
+}
+
+fn is_keyword(tokens: &[Token], index: usize, keyword: &str) -> bool {
+    tokens
+        .get(index)
+        .map(|token| token.is_keyword(keyword))
+        .unwrap_or(false)
+}
+
+fn is_symbol(tokens: &[Token], index: usize, symbol: char) -> bool {
+    tokens
+        .get(index)
+        .map(|token| token.is_symbol(symbol))
+        .unwrap_or(false)
+}
+
+fn tokenize(database_type: &DatabaseType, source: &str) -> anyhow::Result<Vec<Token>> {
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+    let byte_at = |i: usize| chars.get(i).map(|(b, _)| *b).unwrap_or(source.len());
+    let char_at = |i: usize| chars.get(i).map(|(_, c)| *c);
+
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    // mysqldump의 /*!50001 ... */ 안에 있는지 여부
+    let mut in_executable_comment = false;
+
+    while let Some(c) = char_at(i) {
+        // 공백
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        // 한줄 주석
+        if (c == '-' && char_at(i + 1) == Some('-'))
+            || (c == '#' && *database_type == DatabaseType::Mysql)
+        {
+            while let Some(c) = char_at(i) {
+                if c == '\n' {
+                    break;
+                }
+                i += 1;
+            }
+            continue;
+        }
+
+        // psql 메타 명령 (pg_dump가 생성하는 \restrict 등)은 한 줄 전체를 무시합니다.
+        if c == '\\'
+            && *database_type == DatabaseType::Postgres
+            && is_line_start(source, byte_at(i))
+        {
+            while let Some(c) = char_at(i) {
+                if c == '\n' {
+                    break;
+                }
+                i += 1;
+            }
+            continue;
+        }
+
+        // mysqldump의 /*!40101 ... */ 구문은 MySQL이 실행하는 구문이므로, 버전 번호만 건너뛰고 안의 내용을 읽습니다.
+        // 뷰 정의(/*!50001 CREATE ... VIEW ... */)가 이 안에 있습니다.
+        if c == '/'
+            && char_at(i + 1) == Some('*')
+            && char_at(i + 2) == Some('!')
+            && *database_type == DatabaseType::Mysql
+        {
+            i += 3;
+            while char_at(i).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+            }
+            in_executable_comment = true;
+            continue;
+        }
+
+        if c == '*' && char_at(i + 1) == Some('/') && in_executable_comment {
+            i += 2;
+            in_executable_comment = false;
+            continue;
+        }
+
+        // 블록 주석
+        if c == '/' && char_at(i + 1) == Some('*') {
+            i += 2;
+            while char_at(i).is_some() && !(char_at(i) == Some('*') && char_at(i + 1) == Some('/'))
+            {
+                i += 1;
+            }
+            i += 2;
+            continue;
+        }
+
+        let start = byte_at(i);
+
+        // 문자열 리터럴
+        let is_escape_string = (c == 'E' || c == 'e')
+            && char_at(i + 1) == Some('\'')
+            && *database_type == DatabaseType::Postgres;
+        if c == '\'' || is_escape_string {
+            if is_escape_string {
+                i += 1;
+            }
+            let allow_backslash = is_escape_string || *database_type == DatabaseType::Mysql;
+
+            let (value, next) = read_quoted(&char_at, i, '\'', allow_backslash)
+                .ok_or_else(|| anyhow::anyhow!("unterminated string literal at byte {start}"))?;
+            i = next;
+
+            tokens.push(Token {
+                kind: TokenKind::String,
+                value,
+                start,
+                end: byte_at(i),
+            });
+            continue;
+        }
+
+        // 따옴표로 감싼 식별자
+        if c == '"' || (c == '`' && *database_type == DatabaseType::Mysql) {
+            let (value, next) = read_quoted(&char_at, i, c, false)
+                .ok_or_else(|| anyhow::anyhow!("unterminated identifier at byte {start}"))?;
+            i = next;
+
+            tokens.push(Token {
+                kind: TokenKind::QuotedIdentifier,
+                value,
+                start,
+                end: byte_at(i),
+            });
+            continue;
+        }
+
+        // PostgreSQL의 $tag$ ... $tag$ 문자열 (함수 본문 등)
+        if c == '$' && *database_type == DatabaseType::Postgres {
+            let mut j = i + 1;
+            while let Some(c) = char_at(j) {
+                if c.is_alphanumeric() || c == '_' {
+                    j += 1;
+                } else {
+                    break;
+                }
+            }
+
+            if char_at(j) == Some('$') {
+                let tag: String = chars[i..=j].iter().map(|(_, c)| *c).collect();
+                let body_start = byte_at(j + 1);
+
+                let Some(offset) = source[body_start..].find(&tag) else {
+                    return Err(anyhow::anyhow!(
+                        "unterminated dollar-quoted string at byte {start}"
+                    ));
+                };
+                let end = body_start + offset + tag.len();
+
+                tokens.push(Token {
+                    kind: TokenKind::String,
+                    value: source[body_start..body_start + offset].to_string(),
+                    start,
+                    end,
+                });
+
+                while byte_at(i) < end {
+                    i += 1;
+                }
+                continue;
+            }
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            while let Some(c) = char_at(i) {
+                if c.is_alphanumeric() || c == '_' || c == '$' {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Word,
+                value: source[start..byte_at(i)].to_string(),
+                start,
+                end: byte_at(i),
+            });
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            while let Some(c) = char_at(i) {
+                if c.is_ascii_digit() || c == '.' {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Number,
+                value: source[start..byte_at(i)].to_string(),
+                start,
+                end: byte_at(i),
+            });
+            continue;
+        }
+
+        i += 1;
+        tokens.push(Token {
+            kind: TokenKind::Symbol,
+            value: c.to_string(),
+            start,
+            end: byte_at(i),
+        });
+    }
+
+    Ok(tokens)
+}
+
+// 줄의 처음(앞에 공백만 있는 위치)인지 확인합니다.
+fn is_line_start(source: &str, byte: usize) -> bool {
+    let before = source[..byte].trim_end_matches([' ', '\t']);
+
+    before.is_empty() || before.ends_with('\n')
+}
+
+// quote 문자로 감싼 값을 읽고, (벗겨진 값, 다음 위치)를 반환합니다.
+fn read_quoted(
+    char_at: &impl Fn(usize) -> Option<char>,
+    start: usize,
+    quote: char,
+    allow_backslash: bool,
+) -> Option<(String, usize)> {
+    let mut value = String::new();
+    let mut i = start + 1;
+
+    loop {
+        let c = char_at(i)?;
+
+        if allow_backslash && c == '\\' {
+            let escaped = char_at(i + 1)?;
+            value.push(match escaped {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                '0' => '\0',
+                _ => escaped,
+            });
+            i += 2;
+            continue;
+        }
+
+        if c == quote {
+            // 'it''s' 처럼 두번 연속된 quote는 escape 입니다.
+            if char_at(i + 1) == Some(quote) {
+                value.push(quote);
+                i += 2;
+                continue;
+            }
+
+            return Some((value, i + 1));
+        }
+
+        value.push(c);
+        i += 1;
+    }
+}
+
+// open 위치의 여는 괄호와 짝이 맞는 닫는 괄호 위치를 찾습니다.
+fn matching_paren(tokens: &[Token], open: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        if token.is_symbol('(') {
+            depth += 1;
+        } else if token.is_symbol(')') {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+
+    None
+}
+
+// 괄호 밖에 있는 콤마를 기준으로 나눕니다.
+fn split_commas(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is_symbol('(') {
+            depth += 1;
+        } else if token.is_symbol(')') {
+            depth -= 1;
+        } else if token.is_symbol(',') && depth == 0 {
+            result.push(&tokens[start..i]);
+            start = i + 1;
+        }
+    }
+
+    if start < tokens.len() {
+        result.push(&tokens[start..]);
+    }
+
+    result
+}
+
+// 괄호 밖에서 처음으로 나타나는 키워드 위치를 찾습니다.
+fn find_top_level_keyword(tokens: &[Token], keywords: &[&str]) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is_symbol('(') {
+            depth += 1;
+        } else if token.is_symbol(')') {
+            depth -= 1;
+        } else if depth == 0 && keywords.iter().any(|keyword| token.is_keyword(keyword)) {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+const COMMON_COLUMN_OPTIONS: &[&str] = &[
+    "NOT",
+    "NULL",
+    "DEFAULT",
+    "CONSTRAINT",
+    "PRIMARY",
+    "UNIQUE",
+    "REFERENCES",
+    "CHECK",
+    "COLLATE",
+    "GENERATED",
+];
+
+const MYSQL_COLUMN_OPTIONS: &[&str] = &[
+    "AUTO_INCREMENT",
+    "COMMENT",
+    "KEY",
+    "CHARACTER",
+    "CHARSET",
+    "ON",
+    "AS",
+    "VISIBLE",
+    "INVISIBLE",
+    "STORAGE",
+    "COLUMN_FORMAT",
+    "SRID",
+];
+
+struct Parser<'a> {
+    database_type: DatabaseType,
+    source: &'a str,
+    tables: Vec<Table>,
+    table_index: HashMap<String, usize>,
+}
+
+impl Parser<'_> {
+    fn text(&self, tokens: &[Token]) -> String {
+        match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => self.source[first.start..last.end].to_string(),
+            _ => String::new(),
+        }
+    }
+
+    // 식별자를 정규화합니다. PostgreSQL은 따옴표가 없는 식별자를 소문자로 취급합니다.
+    fn identifier(&self, token: &Token) -> String {
+        if token.kind == TokenKind::Word && self.database_type == DatabaseType::Postgres {
+            token.value.to_lowercase()
+        } else {
+            token.value.clone()
+        }
+    }
+
+    // schema.table 형태의 이름을 읽고, (이름, 소비한 토큰 수)를 반환합니다.
+    // public 이외의 schema에 속한 객체는 비교 대상이 아니므로 None을 반환합니다.
+    fn qualified_name(&self, tokens: &[Token]) -> Option<(Option<String>, usize)> {
+        let mut parts = vec![];
+        let mut i = 0;
+
+        while let Some(token) = tokens.get(i) {
+            if !token.is_identifier() {
+                break;
+            }
+            parts.push(self.identifier(token));
+            i += 1;
+
+            if is_symbol(tokens, i, '.') {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        if parts.is_empty() {
+            return None;
+        }
+
+        let name = parts.pop();
+
+        let is_public = match parts.last() {
+            Some(schema) => self.database_type == DatabaseType::Mysql || schema == "public",
+            None => true,
+        };
+
+        Some((if is_public { name } else { None }, i))
+    }
+
+    fn table_mut(&mut self, table_name: &str) -> Option<&mut Table> {
+        let index = *self.table_index.get(table_name)?;
+        self.tables.get_mut(index)
+    }
+
+    fn statement(&mut self, tokens: &[Token]) {
+        let mut i = 0;
+
+        if is_keyword(tokens, i, "CREATE") {
+            i += 1;
+
+            if is_keyword(tokens, i, "OR") && is_keyword(tokens, i + 1, "REPLACE") {
+                i += 2;
+            }
+
+            // MySQL: CREATE ALGORITHM=... DEFINER=`user`@`host` SQL SECURITY ... VIEW
+            loop {
+                if is_keyword(tokens, i, "ALGORITHM") && is_symbol(tokens, i + 1, '=') {
+                    i += 3;
+                } else if is_keyword(tokens, i, "DEFINER") && is_symbol(tokens, i + 1, '=') {
+                    i += 3;
+                    // `user`@`host` 또는 CURRENT_USER()
+                    if is_symbol(tokens, i, '@')
+                        || (is_symbol(tokens, i, '(') && is_symbol(tokens, i + 1, ')'))
+                    {
+                        i += 2;
+                    }
+                } else if is_keyword(tokens, i, "SQL") && is_keyword(tokens, i + 1, "SECURITY") {
+                    i += 3;
+                } else {
+                    break;
+                }
+            }
+
+            while ["UNLOGGED", "TEMPORARY", "TEMP", "GLOBAL", "LOCAL"]
+                .iter()
+                .any(|keyword| is_keyword(tokens, i, keyword))
+            {
+                i += 1;
+            }
+
+            if is_keyword(tokens, i, "TABLE") {
+                self.create_table(&tokens[i + 1..]);
+                return;
+            }
+
+            // 뷰도 테이블 목록에 포함되지만, 컬럼 정의는 알 수 없으므로 존재 여부만 비교합니다.
+            if is_keyword(tokens, i, "VIEW") {
+                self.create_view(&tokens[i + 1..]);
+                return;
+            }
+
+            let mut is_unique = false;
+            while ["UNIQUE", "FULLTEXT", "SPATIAL"]
+                .iter()
+                .any(|keyword| is_keyword(tokens, i, keyword))
+            {
+                is_unique = is_keyword(tokens, i, "UNIQUE");
+                i += 1;
+            }
+
+            if is_keyword(tokens, i, "INDEX") {
+                self.create_index(&tokens[i + 1..], is_unique);
+            }
+        } else if is_keyword(tokens, i, "ALTER") && is_keyword(tokens, i + 1, "TABLE") {
+            self.alter_table(&tokens[i + 2..]);
+        } else if is_keyword(tokens, i, "COMMENT") && is_keyword(tokens, i + 1, "ON") {
+            self.comment_on(&tokens[i + 2..]);
+        }
+    }
+
+    fn create_table(&mut self, tokens: &[Token]) {
+        let mut i = 0;
+
+        if is_keyword(tokens, i, "IF")
+            && is_keyword(tokens, i + 1, "NOT")
+            && is_keyword(tokens, i + 2, "EXISTS")
+        {
+            i += 3;
+        }
+
+        let Some((table_name, consumed)) = tokens
+            .get(i..)
+            .and_then(|tokens| self.qualified_name(tokens))
+        else {
+            return;
+        };
+        i += consumed;
+
+        let Some(table_name) = table_name else {
+            return;
+        };
+
+        // CREATE TABLE ... AS SELECT, PARTITION OF 등은 지원하지 않습니다.
+        if !is_symbol(tokens, i, '(') {
+            log::warn!("unsupported CREATE TABLE statement: {table_name}");
+            return;
+        }
+
+        let Some(close) = matching_paren(tokens, i) else {
+            return;
+        };
+
+        self.table_index
+            .insert(table_name.clone(), self.tables.len());
+        self.tables.push(Table {
+            name: table_name.clone(),
+            ..Default::default()
+        });
+
+        for element in split_commas(&tokens[i + 1..close]) {
+            self.table_element(&table_name, element);
+        }
+
+        // 테이블 옵션 (MySQL: COMMENT='...')
+        let options = &tokens[close + 1..];
+        if let Some(position) = find_top_level_keyword(options, &["COMMENT"]) {
+            let mut j = position + 1;
+            if is_symbol(options, j, '=') {
+                j += 1;
+            }
+
+            if let Some(comment) = options.get(j).filter(|t| t.kind == TokenKind::String) {
+                let comment = comment.value.clone();
+                if let Some(table) = self.table_mut(&table_name) {
+                    table.comment = comment;
+                }
+            }
+        }
+    }
+
+    fn create_view(&mut self, tokens: &[Token]) {
+        let mut i = 0;
+
+        if is_keyword(tokens, i, "IF")
+            && is_keyword(tokens, i + 1, "NOT")
+            && is_keyword(tokens, i + 2, "EXISTS")
+        {
+            i += 3;
+        }
+
+        let Some((Some(view_name), consumed)) = tokens
+            .get(i..)
+            .and_then(|tokens| self.qualified_name(tokens))
+        else {
+            return;
+        };
+
+        // 잘린 문장은 무시합니다. 뷰 정의는 AS나 컬럼 목록이 이어집니다.
+        if !is_keyword(tokens, i + consumed, "AS") && !is_symbol(tokens, i + consumed, '(') {
+            return;
+        }
+
+        if self.table_index.contains_key(&view_name) {
+            return;
+        }
+
+        self.table_index
+            .insert(view_name.clone(), self.tables.len());
+        self.tables.push(Table {
+            name: view_name,
+            ..Default::default()
+        });
+    }
+
+    fn table_element(&mut self, table_name: &str, tokens: &[Token]) {
+        let Some(first) = tokens.first() else {
+            return;
+        };
+
+        if first.is_keyword("CONSTRAINT") {
+            let name = tokens.get(1).map(|t| self.identifier(t));
+            self.constraint(table_name, name, &tokens[2.min(tokens.len())..]);
+            return;
+        }
+
+        let is_constraint = ["PRIMARY", "UNIQUE", "FOREIGN", "CHECK", "EXCLUDE", "LIKE"]
+            .iter()
+            .any(|keyword| first.is_keyword(keyword))
+            || (self.database_type == DatabaseType::Mysql
+                && ["KEY", "INDEX", "FULLTEXT", "SPATIAL"]
+                    .iter()
+                    .any(|keyword| first.is_keyword(keyword)));
+
+        if is_constraint {
+            self.constraint(table_name, None, tokens);
+        } else {
+            self.column_definition(table_name, tokens);
+        }
+    }
+
+    // 인덱스 컬럼 목록을 읽습니다. 표현식 인덱스 요소는 실제 데이터베이스에서도 조회되지 않으므로 제외합니다.
+    fn index_columns(&self, tokens: &[Token]) -> Vec<String> {
+        split_commas(tokens)
+            .into_iter()
+            .filter_map(|element| {
+                let first = element.first()?;
+                if !first.is_identifier() {
+                    return None;
+                }
+
+                // MySQL의 name(10)은 prefix 길이 지정이지만, PostgreSQL의 lower(x)는 표현식입니다.
+                if is_symbol(element, 1, '(') && self.database_type == DatabaseType::Postgres {
+                    return None;
+                }
+
+                Some(self.identifier(first))
+            })
+            .collect()
+    }
+
+    // 괄호로 감싼 컬럼 목록을 읽고, (컬럼 목록, 소비한 토큰 수)를 반환합니다.
+    fn parenthesized_columns(&self, tokens: &[Token]) -> Option<(Vec<String>, usize)> {
+        let open = tokens.iter().position(|t| t.is_symbol('('))?;
+        let close = matching_paren(tokens, open)?;
+
+        Some((self.index_columns(&tokens[open + 1..close]), close + 1))
+    }
+
+    fn add_index(&mut self, table_name: &str, index: Index) {
+        let Some(table) = self.table_mut(table_name) else {
+            return;
+        };
+
+        if index.columns.is_empty() || table.indexes.iter().any(|i| i.name == index.name) {
+            return;
+        }
+
+        table.indexes.push(index);
+    }
+
+    fn set_not_null(&mut self, table_name: &str, columns: &[String]) {
+        if let Some(table) = self.table_mut(table_name) {
+            for column in table.columns.iter_mut() {
+                if columns.contains(&column.name) {
+                    column.nullable = false;
+                }
+            }
+        }
+    }
+
+    fn primary_key(&mut self, table_name: &str, name: Option<String>, columns: Vec<String>) {
+        self.set_not_null(table_name, &columns);
+
+        let name = match self.database_type {
+            DatabaseType::Postgres => name.unwrap_or_else(|| format!("{table_name}_pkey")),
+            DatabaseType::Mysql => "PRIMARY".to_string(),
+        };
+
+        self.add_index(
+            table_name,
+            Index {
+                name,
+                columns,
+                predicate: "".to_string(),
+                is_unique: true,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn unique_key(&mut self, table_name: &str, name: Option<String>, columns: Vec<String>) {
+        let name = match (name, &self.database_type) {
+            (Some(name), _) => name,
+            (None, DatabaseType::Postgres) => format!("{table_name}_{}_key", columns.join("_")),
+            (None, DatabaseType::Mysql) => columns.first().cloned().unwrap_or_default(),
+        };
+
+        self.add_index(
+            table_name,
+            Index {
+                name,
+                columns,
+                predicate: "".to_string(),
+                is_unique: true,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn foreign_key(
+        &mut self,
+        table_name: &str,
+        name: Option<String>,
+        columns: Vec<String>,
+        tokens: &[Token],
+    ) {
+        let Some(position) = tokens.iter().position(|t| t.is_keyword("REFERENCES")) else {
+            return;
+        };
+        let Some((Some(foreign_table_name), consumed)) =
+            self.qualified_name(&tokens[position + 1..])
+        else {
+            return;
+        };
+        let foreign_columns = self
+            .parenthesized_columns(&tokens[position + 1 + consumed..])
+            .map(|(columns, _)| columns)
+            .unwrap_or_default();
+
+        let is_mysql = self.database_type == DatabaseType::Mysql;
+
+        let Some(table) = self.table_mut(table_name) else {
+            return;
+        };
+
+        let name = name.unwrap_or_else(|| {
+            if is_mysql {
+                format!("{table_name}_ibfk_{}", table.foreign_keys().len() + 1)
+            } else {
+                format!("{table_name}_{}_fkey", columns.join("_"))
+            }
+        });
+
+        for (i, column) in columns.into_iter().enumerate() {
+            table.constraints.push(
+                ForeignKey {
+                    name: name.clone(),
+                    column: vec![column],
+                    foreign_column: SelectColumn {
+                        table_name: foreign_table_name.clone(),
+                        column_name: foreign_columns.get(i).cloned().unwrap_or_default(),
+                    },
+                }
+                .into(),
+            );
+        }
+    }
+
+    fn constraint(&mut self, table_name: &str, name: Option<String>, tokens: &[Token]) {
+        let Some(first) = tokens.first() else {
+            return;
+        };
+
+        if first.is_keyword("PRIMARY") {
+            if let Some((columns, _)) = self.parenthesized_columns(tokens) {
+                self.primary_key(table_name, name, columns);
+            }
+        } else if first.is_keyword("UNIQUE") {
+            // MySQL: UNIQUE [KEY|INDEX] [name] (columns)
+            let mut i = 1;
+            if is_keyword(tokens, i, "KEY") || is_keyword(tokens, i, "INDEX") {
+                i += 1;
+            }
+            let index_name = tokens
+                .get(i)
+                .filter(|t| t.is_identifier())
+                .map(|t| self.identifier(t));
+
+            if let Some((columns, _)) = self.parenthesized_columns(&tokens[i..]) {
+                self.unique_key(table_name, index_name.or(name), columns);
+            }
+        } else if first.is_keyword("FOREIGN") {
+            if let Some((columns, consumed)) = self.parenthesized_columns(tokens) {
+                // MySQL은 FOREIGN KEY [index_name] (columns) 형태를 허용합니다.
+                let index_name = tokens
+                    .get(2)
+                    .filter(|t| t.is_identifier() && self.database_type == DatabaseType::Mysql)
+                    .map(|t| self.identifier(t));
+
+                self.foreign_key(
+                    table_name,
+                    name.or(index_name),
+                    columns,
+                    &tokens[consumed..],
+                );
+            }
+        } else if ["KEY", "INDEX", "FULLTEXT", "SPATIAL"]
+            .iter()
+            .any(|keyword| first.is_keyword(keyword))
+        {
+            let mut i = 1;
+            if is_keyword(tokens, i, "KEY") || is_keyword(tokens, i, "INDEX") {
+                i += 1;
+            }
+            let index_name = tokens
+                .get(i)
+                .filter(|t| t.is_identifier())
+                .map(|t| self.identifier(t));
+
+            if let Some((columns, _)) = self.parenthesized_columns(&tokens[i..]) {
+                let index_name = index_name
+                    .or(name)
+                    .unwrap_or_else(|| columns.first().cloned().unwrap_or_default());
+
+                self.add_index(
+                    table_name,
+                    Index {
+                        name: index_name,
+                        columns,
+                        predicate: "".to_string(),
+                        is_unique: false,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+    }
+
+    fn column_definition(&mut self, table_name: &str, tokens: &[Token]) {
+        let Some(first) = tokens.first().filter(|t| t.is_identifier()) else {
+            return;
+        };
+        let column_name = self.identifier(first);
+
+        let database_type = self.database_type.clone();
+        let is_option = |token: &Token, is_first: bool| {
+            COMMON_COLUMN_OPTIONS.iter().any(|k| token.is_keyword(k))
+                || (database_type == DatabaseType::Mysql
+                    && !is_first
+                    && MYSQL_COLUMN_OPTIONS.iter().any(|k| token.is_keyword(k)))
+        };
+
+        // 1. 타입
+        let mut i = 1;
+        let mut depth = 0;
+        while let Some(token) = tokens.get(i) {
+            if token.is_symbol('(') {
+                depth += 1;
+            } else if token.is_symbol(')') {
+                depth -= 1;
+            } else if depth == 0 && is_option(token, i == 1) {
+                break;
+            }
+            i += 1;
+        }
+        let raw_type = self.text(&tokens[1..i]);
+
+        let mut column = Column {
+            name: column_name.clone(),
+            nullable: true,
+            ..Default::default()
+        };
+        let mut constraint_name = None;
+
+        match self.database_type {
+            DatabaseType::Postgres => {
+                column.data_type = normalize_postgres_type(&raw_type);
+
+                let lower_type = raw_type.to_lowercase();
+                if lower_type.contains("serial") {
+                    let sequence_name =
+                        quote_postgres_identifier(&format!("{table_name}_{column_name}_seq"));
+
+                    column.nullable = false;
+                    column.default = format!("nextval('{sequence_name}'::regclass)");
+                }
+            }
+            DatabaseType::Mysql => {
+                column.data_type = normalize_mysql_type(&raw_type);
+            }
+        }
+
+        // 2. 컬럼 옵션
+        let mut primary_key = false;
+        let mut unique_key = false;
+
+        while let Some(token) = tokens.get(i) {
+            if token.is_keyword("NOT") && is_keyword(tokens, i + 1, "NULL") {
+                column.nullable = false;
+                i += 2;
+            } else if token.is_keyword("NULL") {
+                column.nullable = true;
+                i += 1;
+            } else if token.is_keyword("DEFAULT") {
+                let start = i + 1;
+                let mut end = start + 1;
+                let mut depth = 0;
+                for (j, token) in tokens.iter().enumerate().skip(start) {
+                    end = j + 1;
+                    if token.is_symbol('(') {
+                        depth += 1;
+                    } else if token.is_symbol(')') {
+                        depth -= 1;
+                    } else if j > start && depth == 0 && is_option(token, false) {
+                        end = j;
+                        break;
+                    }
+                }
+                let end = end.min(tokens.len());
+
+                let default_tokens = &tokens[start.min(end)..end];
+                column.default = self.normalize_default(default_tokens);
+
+                // PostgreSQL은 타입 캐스팅이 없는 문자열 기본값에 캐스팅을 붙여서 저장합니다. ('abc' => 'abc'::text)
+                if self.database_type == DatabaseType::Postgres
+                    && default_tokens.len() == 1
+                    && default_tokens[0].kind == TokenKind::String
+                {
+                    if let Some(cast) = postgres_literal_cast(&raw_type) {
+                        column.default = format!("{}::{cast}", column.default);
+                    }
+                }
+
+                i = end;
+            } else if token.is_keyword("CONSTRAINT") {
+                constraint_name = tokens.get(i + 1).map(|t| self.identifier(t));
+                i += 2;
+            } else if token.is_keyword("PRIMARY") {
+                primary_key = true;
+                column.nullable = false;
+                i += 2;
+            } else if token.is_keyword("UNIQUE") {
+                unique_key = true;
+                i += 1;
+            } else if token.is_keyword("REFERENCES") {
+                let end = find_top_level_keyword(&tokens[i + 1..], COMMON_COLUMN_OPTIONS)
+                    .map(|p| i + 1 + p)
+                    .unwrap_or(tokens.len());
+                let references = tokens[i..end].to_vec();
+                let name = constraint_name.take();
+
+                // 컬럼이 테이블에 추가된 뒤에 외래키를 추가합니다.
+                self.push_column(table_name, &column);
+                self.foreign_key(table_name, name, vec![column_name.clone()], &references);
+                i = end;
+            } else if token.is_keyword("AUTO_INCREMENT") {
+                column.is_auto_increment = true;
+                i += 1;
+            } else if token.is_keyword("COMMENT") {
+                if let Some(comment) = tokens.get(i + 1).filter(|t| t.kind == TokenKind::String) {
+                    column.comment = comment.value.clone();
+                }
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+
+        self.push_column(table_name, &column);
+
+        if primary_key {
+            self.primary_key(
+                table_name,
+                constraint_name.take(),
+                vec![column_name.clone()],
+            );
+        }
+
+        if unique_key {
+            self.unique_key(table_name, constraint_name.take(), vec![column_name]);
+        }
+    }
+
+    // 컬럼을 추가하거나, 이미 있다면 갱신합니다.
+    fn push_column(&mut self, table_name: &str, column: &Column) {
+        let Some(table) = self.table_mut(table_name) else {
+            return;
+        };
+
+        match table.columns.iter_mut().find(|c| c.name == column.name) {
+            Some(existing) => *existing = column.clone(),
+            None => table.columns.push(column.clone()),
+        }
+    }
+
+    // 기본값 표현식을 information_schema.columns.column_default 형태로 정규화합니다.
+    fn normalize_default(&self, tokens: &[Token]) -> String {
+        if tokens.len() == 1 && tokens[0].is_keyword("NULL") {
+            return "".to_string();
+        }
+
+        match self.database_type {
+            DatabaseType::Postgres => self.text(tokens).replace("'public.", "'"),
+            DatabaseType::Mysql => {
+                if tokens.len() == 1 && tokens[0].kind == TokenKind::String {
+                    return tokens[0].value.clone();
+                }
+
+                // MySQL 8의 DEFAULT (expr) 형태
+                if tokens.first().map(|t| t.is_symbol('(')).unwrap_or(false)
+                    && matching_paren(tokens, 0) == Some(tokens.len() - 1)
+                {
+                    return self.text(&tokens[1..tokens.len() - 1]);
+                }
+
+                let text = self.text(tokens);
+                match text.to_uppercase().as_str() {
+                    "NOW()" | "CURRENT_TIMESTAMP()" | "CURRENT_TIMESTAMP" => {
+                        "CURRENT_TIMESTAMP".to_string()
+                    }
+                    _ => text,
+                }
+            }
+        }
+    }
+
+    fn create_index(&mut self, tokens: &[Token], is_unique: bool) {
+        let Some(on) = tokens.iter().position(|t| t.is_keyword("ON")) else {
+            return;
+        };
+
+        let index_name = tokens[..on]
+            .iter()
+            .find(|t| {
+                t.is_identifier()
+                    && !["CONCURRENTLY", "IF", "NOT", "EXISTS"]
+                        .iter()
+                        .any(|k| t.is_keyword(k))
+            })
+            .map(|t| self.identifier(t));
+
+        let mut i = on + 1;
+        if is_keyword(tokens, i, "ONLY") {
+            i += 1;
+        }
+
+        let Some((Some(table_name), consumed)) = self.qualified_name(&tokens[i..]) else {
+            return;
+        };
+        i += consumed;
+
+        let Some(open) = tokens[i..]
+            .iter()
+            .position(|t| t.is_symbol('('))
+            .map(|p| i + p)
+        else {
+            return;
+        };
+        let Some(close) = matching_paren(tokens, open) else {
+            return;
+        };
+
+        let columns = self.index_columns(&tokens[open + 1..close]);
+
+        // pg_get_expr()는 조건식 전체를 괄호로 감싸서 반환합니다.
+        let predicate = match find_top_level_keyword(&tokens[close + 1..], &["WHERE"]) {
+            Some(position) => {
+                let predicate_tokens = &tokens[close + 2 + position..];
+                let predicate = self.text(predicate_tokens);
+                let is_wrapped = is_symbol(predicate_tokens, 0, '(')
+                    && matching_paren(predicate_tokens, 0) == Some(predicate_tokens.len() - 1);
+
+                // 컬럼 하나(WHERE published)나 함수 호출은 괄호로 감싸지 않습니다.
+                let is_simple = match predicate_tokens {
+                    [token] => token.is_identifier(),
+                    [token, ..] => {
+                        token.is_identifier()
+                            && is_symbol(predicate_tokens, 1, '(')
+                            && matching_paren(predicate_tokens, 1)
+                                == Some(predicate_tokens.len() - 1)
+                    }
+                    [] => true,
+                };
+
+                if is_wrapped || is_simple {
+                    predicate
+                } else {
+                    format!("({predicate})")
+                }
+            }
+            None => "".to_string(),
+        };
+
+        let Some(index_name) = index_name else {
+            return;
+        };
+
+        self.add_index(
+            &table_name,
+            Index {
+                name: index_name,
+                columns,
+                predicate,
+                is_unique,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn alter_table(&mut self, tokens: &[Token]) {
+        let mut i = 0;
+        while ["ONLY", "IF", "EXISTS"]
+            .iter()
+            .any(|keyword| is_keyword(tokens, i, keyword))
+        {
+            i += 1;
+        }
+
+        let Some((Some(table_name), consumed)) = self.qualified_name(&tokens[i..]) else {
+            return;
+        };
+        i += consumed;
+
+        if !self.table_index.contains_key(&table_name) {
+            return;
+        }
+
+        for action in split_commas(&tokens[i..]) {
+            if is_keyword(action, 0, "ADD") {
+                let mut j = 1;
+                if is_keyword(action, j, "COLUMN") {
+                    j += 1;
+                }
+                self.table_element(&table_name, &action[j..]);
+            } else if is_keyword(action, 0, "ALTER") {
+                let mut j = 1;
+                if is_keyword(action, j, "COLUMN") {
+                    j += 1;
+                }
+                let Some(column_name) = action.get(j).map(|t| self.identifier(t)) else {
+                    continue;
+                };
+                j += 1;
+
+                let default = if is_keyword(action, j, "SET")
+                    && is_keyword(action, j + 1, "DEFAULT")
+                {
+                    Some(self.normalize_default(&action[j + 2..]))
+                } else if is_keyword(action, j, "DROP") && is_keyword(action, j + 1, "DEFAULT") {
+                    Some("".to_string())
+                } else {
+                    None
+                };
+                let nullable = if is_keyword(action, j + 1, "NOT") {
+                    if is_keyword(action, j, "SET") {
+                        Some(false)
+                    } else {
+                        Some(true)
+                    }
+                } else {
+                    None
+                };
+
+                if let Some(table) = self.table_mut(&table_name) {
+                    if let Some(column) = table.columns.iter_mut().find(|c| c.name == column_name) {
+                        if let Some(default) = default {
+                            column.default = default;
+                        }
+                        if let Some(nullable) = nullable {
+                            column.nullable = nullable;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn comment_on(&mut self, tokens: &[Token]) {
+        let is_table = is_keyword(tokens, 0, "TABLE");
+        let is_column = is_keyword(tokens, 0, "COLUMN");
+        if !is_table && !is_column {
+            return;
+        }
+
+        let Some(is_position) = tokens.iter().position(|t| t.is_keyword("IS")) else {
+            return;
+        };
+
+        let mut parts: Vec<String> = tokens[1..is_position]
+            .iter()
+            .filter(|t| t.is_identifier())
+            .map(|t| self.identifier(t))
+            .collect();
+
+        let comment = tokens
+            .get(is_position + 1)
+            .filter(|t| t.kind == TokenKind::String)
+            .map(|t| t.value.clone())
+            .unwrap_or_default();
+
+        let column_name = if is_column { parts.pop() } else { None };
+        let Some(table_name) = parts.pop() else {
+            return;
+        };
+        if parts
+            .last()
+            .map(|schema| schema != "public")
+            .unwrap_or(false)
+        {
+            return;
+        }
+
+        let Some(table) = self.table_mut(&table_name) else {
+            return;
+        };
+
+        match column_name {
+            Some(column_name) => {
+                if let Some(column) = table.columns.iter_mut().find(|c| c.name == column_name) {
+                    column.comment = comment;
+                }
+            }
+            None => table.comment = comment,
+        }
+    }
+
+    // MySQL은 외래키 컬럼으로 시작하는 인덱스가 없으면 인덱스를 자동 생성합니다.
+    // 제약조건 이름을 지정했다면 그 이름을, 아니라면 첫번째 컬럼 이름을 인덱스 이름으로 사용합니다.
+    fn add_implicit_foreign_key_indexes(&mut self) {
+        for table in self.tables.iter_mut() {
+            let generated_prefix = format!("{}_ibfk_", table.name);
+
+            let mut foreign_keys: Vec<(String, Vec<String>)> = vec![];
+
+            for foreign_key in table.foreign_keys() {
+                match foreign_keys
+                    .iter_mut()
+                    .find(|(name, _)| *name == foreign_key.name)
+                {
+                    Some((_, columns)) => columns.extend(foreign_key.column.clone()),
+                    None => {
+                        foreign_keys.push((foreign_key.name.clone(), foreign_key.column.clone()))
+                    }
+                }
+            }
+
+            for (name, columns) in foreign_keys {
+                let has_index = table
+                    .indexes
+                    .iter()
+                    .any(|index| index.columns.starts_with(&columns));
+
+                if !has_index {
+                    let name = if name.starts_with(&generated_prefix) {
+                        columns.first().cloned().unwrap_or(name)
+                    } else {
+                        name
+                    };
+
+                    table.indexes.push(Index {
+                        name,
+                        columns,
+                        predicate: "".to_string(),
+                        is_unique: false,
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+    }
+}
+
+// 타입 문자열에서 괄호 부분을 분리합니다. ("numeric(10, 2)" => ("numeric", Some("10, 2")))
+fn split_type_modifier(raw_type: &str) -> (String, Option<String>) {
+    let mut base = String::new();
+    let mut modifier = None;
+    let mut depth = 0;
+    let mut current = String::new();
+
+    for c in raw_type.chars() {
+        match c {
+            '(' => {
+                depth += 1;
+                if depth > 1 {
+                    current.push(c);
+                }
+            }
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    modifier.get_or_insert(current.clone());
+                    current.clear();
+                } else {
+                    current.push(c);
+                }
+            }
+            _ if depth > 0 => current.push(c),
+            _ => base.push(c),
+        }
+    }
+
+    let base = base.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    (base, modifier)
+}
+
+// information_schema.columns.data_type 형태로 정규화합니다. (postgres::describe_table 참조)
+pub(super) fn normalize_postgres_type(raw_type: &str) -> String {
+    let (base, modifier) = split_type_modifier(&raw_type.to_lowercase());
+
+    if base.ends_with("[]") || base.starts_with('_') || base.contains(" array") {
+        return "ARRAY".to_string();
+    }
+
+    let base = base
+        .trim_start_matches("pg_catalog.")
+        .trim_matches('"')
+        .to_string();
+
+    let length = modifier
+        .map(|modifier| modifier.trim().to_string())
+        .unwrap_or_else(|| "0".to_string());
+
+    let data_type = match base.as_str() {
+        "character varying" | "varchar" => return format!("varchar({length})"),
+        "int" | "int4" | "integer" | "serial" | "serial4" => "integer",
+        "int8" | "bigint" | "bigserial" | "serial8" => "bigint",
+        "int2" | "smallint" | "smallserial" | "serial2" => "smallint",
+        "bool" | "boolean" => "boolean",
+        "float8" | "double precision" => "double precision",
+        "float4" | "real" => "real",
+        "float" => "double precision",
+        "decimal" | "numeric" => "numeric",
+        "char" | "character" | "bpchar" => "character",
+        "timestamp" | "timestamp without time zone" => "timestamp without time zone",
+        "timestamptz" | "timestamp with time zone" => "timestamp with time zone",
+        "time" | "time without time zone" => "time without time zone",
+        "timetz" | "time with time zone" => "time with time zone",
+        "varbit" | "bit varying" => "bit varying",
+        "text" | "bytea" | "date" | "uuid" | "json" | "jsonb" | "xml" | "inet" | "cidr"
+        | "macaddr" | "macaddr8" | "money" | "bit" | "tsvector" | "tsquery" | "point" | "line"
+        | "lseg" | "box" | "path" | "polygon" | "circle" | "oid" | "name" | "pg_lsn"
+        | "int4range" | "int8range" | "numrange" | "tsrange" | "tstzrange" | "daterange" => {
+            base.as_str()
+        }
+        _ if base.starts_with("interval") => "interval",
+        _ => "USER-DEFINED",
+    };
+
+    data_type.to_string()
+}
+
+// 문자열 기본값에 붙는 캐스팅 이름을 반환합니다. 정규화할 수 없는 타입이면 None을 반환합니다.
+fn postgres_literal_cast(raw_type: &str) -> Option<String> {
+    let (base, _) = split_type_modifier(&raw_type.to_lowercase());
+
+    if let Some(element) = base.strip_suffix("[]") {
+        return postgres_literal_cast(element).map(|cast| format!("{cast}[]"));
+    }
+
+    let cast = match base.as_str() {
+        "character varying" | "varchar" => "character varying",
+        "char" | "character" | "bpchar" => "bpchar",
+        "text" | "json" | "jsonb" | "uuid" | "date" | "bytea" => base.as_str(),
+        _ => return None,
+    };
+
+    Some(cast.to_string())
+}
+
+// 소문자/숫자/_ 이외의 문자가 포함된 식별자는 따옴표로 감쌉니다.
+fn quote_postgres_identifier(identifier: &str) -> String {
+    let is_plain = identifier
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if is_plain {
+        identifier.to_string()
+    } else {
+        format!("\"{identifier}\"")
+    }
+}
+
+// information_schema.columns.column_type 형태로 정규화합니다. (mysql::describe_table 참조)
+pub(super) fn normalize_mysql_type(raw_type: &str) -> String {
+    let mut normalized = String::new();
+    let mut in_string = false;
+
+    for c in raw_type.chars() {
+        if c == '\'' {
+            in_string = !in_string;
+        }
+
+        if in_string {
+            normalized.push(c);
+        } else if c.is_whitespace() {
+            if !normalized.ends_with(' ')
+                && !normalized.ends_with('(')
+                && !normalized.ends_with(',')
+            {
+                normalized.push(' ');
+            }
+        } else {
+            if (c == '(' || c == ')' || c == ',') && normalized.ends_with(' ') {
+                normalized.pop();
+            }
+            normalized.extend(c.to_lowercase());
+        }
+    }
+
+    let normalized = normalized.trim().to_string();
+
+    let (base, rest) = match normalized.find(['(', ' ']) {
+        Some(position) => normalized.split_at(position),
+        None => (normalized.as_str(), ""),
+    };
+
+    match base {
+        "integer" => format!("int{rest}"),
+        "bool" | "boolean" => "tinyint(1)".to_string(),
+        "dec" | "numeric" | "fixed" => format!("decimal{rest}"),
+        _ => normalized,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // pg_dump --schema-only 출력 (src/sql/testdata/pg_dump.sql)
+    const PG_DUMP: &str = include_str!("testdata/pg_dump.sql");
+    // mysqldump --no-data 출력 (src/sql/testdata/mysqldump.sql)
+    const MYSQLDUMP: &str = include_str!("testdata/mysqldump.sql");
+
+    fn table<'a>(tables: &'a [Table], name: &str) -> &'a Table {
+        tables
+            .iter()
+            .find(|table| table.name == name)
+            .unwrap_or_else(|| panic!("table {name} is not parsed"))
+    }
+
+    fn column<'a>(table: &'a Table, name: &str) -> &'a Column {
+        table
+            .columns
+            .iter()
+            .find(|column| column.name == name)
+            .unwrap_or_else(|| panic!("column {}.{name} is not parsed", table.name))
+    }
+
+    fn index<'a>(table: &'a Table, name: &str) -> &'a Index {
+        table
+            .indexes
+            .iter()
+            .find(|index| index.name == name)
+            .unwrap_or_else(|| panic!("index {}.{name} is not parsed", table.name))
+    }
+
+    fn table_names(tables: &[Table]) -> Vec<&str> {
+        tables.iter().map(|table| table.name.as_str()).collect()
+    }
+
+    #[test]
+    fn pg_dump_tables_and_views() {
+        let tables = parse_ddl(&DatabaseType::Postgres, PG_DUMP).unwrap();
+
+        // audit 스키마의 테이블은 비교 대상이 아닙니다.
+        assert_eq!(table_names(&tables), ["users", "active_users", "posts"]);
+    }
+
+    #[test]
+    fn pg_dump_columns() {
+        let tables = parse_ddl(&DatabaseType::Postgres, PG_DUMP).unwrap();
+        let users = table(&tables, "users");
+
+        assert_eq!(users.comment, "app users");
+
+        let id = column(users, "id");
+        assert_eq!(id.data_type, "bigint");
+        assert_eq!(id.default, "nextval('users_id_seq'::regclass)");
+        assert!(!id.nullable);
+
+        let email = column(users, "email");
+        assert_eq!(email.data_type, "varchar(320)");
+        assert_eq!(email.comment, "login email");
+
+        // 따옴표로 감싼 이름은 대소문자와 공백을 그대로 둡니다.
+        let display_name = column(users, "Display Name");
+        assert_eq!(display_name.default, "'anon'::text");
+        assert!(display_name.nullable);
+
+        assert_eq!(
+            column(users, "created_at").data_type,
+            "timestamp with time zone"
+        );
+    }
+
+    #[test]
+    fn pg_dump_alter_table_constraints() {
+        let tables = parse_ddl(&DatabaseType::Postgres, PG_DUMP).unwrap();
+        let posts = table(&tables, "posts");
+
+        let primary_key = index(posts, "posts_pkey");
+        assert_eq!(primary_key.columns, ["id"]);
+        assert!(primary_key.is_unique);
+
+        let foreign_keys = posts.foreign_keys();
+        assert_eq!(foreign_keys.len(), 1);
+        assert_eq!(foreign_keys[0].name, "posts_user_id_fkey");
+        assert_eq!(foreign_keys[0].column, ["user_id"]);
+        assert_eq!(foreign_keys[0].foreign_column.table_name, "users");
+        assert_eq!(foreign_keys[0].foreign_column.column_name, "id");
+
+        assert!(index(table(&tables, "users"), "users_pkey").is_unique);
+    }
+
+    #[test]
+    fn pg_dump_create_index() {
+        let tables = parse_ddl(&DatabaseType::Postgres, PG_DUMP).unwrap();
+
+        let unique = index(table(&tables, "users"), "users_email_key");
+        assert_eq!(unique.columns, ["email"]);
+        assert!(unique.is_unique);
+
+        let partial = index(table(&tables, "posts"), "idx_posts_published");
+        assert_eq!(partial.columns, ["user_id", "title"]);
+        assert_eq!(partial.predicate, "published");
+        assert!(!partial.is_unique);
+    }
+
+    #[test]
+    fn pg_index_predicate_is_wrapped_like_pg_get_expr() {
+        let ddl = "
+            CREATE TABLE public.t (a integer, b text);
+            CREATE INDEX i1 ON public.t USING btree (a) WHERE (a IS NOT NULL);
+            CREATE INDEX i2 ON public.t USING btree (a) WHERE a > 0;
+            CREATE INDEX i3 ON public.t USING btree (b) WHERE lower(b) IS NULL;
+        ";
+        let tables = parse_ddl(&DatabaseType::Postgres, ddl).unwrap();
+        let t = table(&tables, "t");
+
+        assert_eq!(index(t, "i1").predicate, "(a IS NOT NULL)");
+        assert_eq!(index(t, "i2").predicate, "(a > 0)");
+        assert_eq!(index(t, "i3").predicate, "(lower(b) IS NULL)");
+    }
+
+    #[test]
+    fn pg_quoted_and_schema_qualified_names() {
+        let ddl = r#"
+            CREATE TABLE public."Order Items" (
+                "Id" integer NOT NULL,
+                OrderId integer
+            );
+            CREATE TABLE audit.log (id integer);
+            CREATE TABLE "audit"."events" (id integer);
+            ALTER TABLE ONLY public."Order Items"
+                ADD CONSTRAINT "Order Items_pkey" PRIMARY KEY ("Id");
+            ALTER TABLE ONLY audit.log
+                ADD CONSTRAINT log_pkey PRIMARY KEY (id);
+        "#;
+        let tables = parse_ddl(&DatabaseType::Postgres, ddl).unwrap();
+
+        assert_eq!(table_names(&tables), ["Order Items"]);
+
+        let order_items = table(&tables, "Order Items");
+        // 따옴표가 없는 이름은 소문자로 바뀝니다.
+        assert_eq!(
+            order_items
+                .columns
+                .iter()
+                .map(|column| column.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Id", "orderid"]
+        );
+        assert_eq!(index(order_items, "Order Items_pkey").columns, ["Id"]);
+    }
+
+    #[test]
+    fn pg_psql_meta_commands_are_skipped() {
+        // pg_dump는 \restrict 줄 뒤에 세미콜론을 붙이지 않습니다.
+        let ddl = "\\restrict abc123\nCREATE TABLE public.t (id integer);\n\\unrestrict abc123\n";
+        let tables = parse_ddl(&DatabaseType::Postgres, ddl).unwrap();
+
+        assert_eq!(table_names(&tables), ["t"]);
+    }
+
+    #[test]
+    fn truncated_statements_do_not_panic() {
+        for ddl in [
+            "CREATE TABLE",
+            "CREATE TABLE IF",
+            "CREATE TABLE IF NOT",
+            "CREATE TABLE IF NOT EXISTS",
+            "CREATE VIEW IF NOT",
+            "CREATE UNIQUE INDEX",
+            "CREATE INDEX i ON",
+            "ALTER TABLE",
+            "ALTER TABLE ONLY",
+            "COMMENT ON",
+        ] {
+            for database_type in [DatabaseType::Postgres, DatabaseType::Mysql] {
+                let tables = parse_ddl(&database_type, ddl).unwrap();
+                assert!(tables.is_empty(), "{ddl}");
+            }
+        }
+    }
+
+    #[test]
+    fn mysqldump_tables_and_views() {
+        let tables = parse_ddl(&DatabaseType::Mysql, MYSQLDUMP).unwrap();
+
+        // 뷰는 /*!50001 ... */ 블록 안에 있고, 임시 구조와 최종 정의로 두 번 나옵니다.
+        assert_eq!(table_names(&tables), ["active_users", "posts", "users"]);
+    }
+
+    #[test]
+    fn mysqldump_columns() {
+        let tables = parse_ddl(&DatabaseType::Mysql, MYSQLDUMP).unwrap();
+        let posts = table(&tables, "posts");
+
+        assert_eq!(posts.comment, "blog posts");
+
+        let id = column(posts, "id");
+        assert_eq!(id.data_type, "int");
+        assert!(id.is_auto_increment);
+        assert!(!id.nullable);
+
+        let title = column(posts, "title");
+        assert_eq!(title.data_type, "varchar(100)");
+        assert_eq!(title.comment, "post title");
+        assert_eq!(title.default, "");
+        assert!(title.nullable);
+
+        assert_eq!(column(posts, "published").default, "0");
+        assert_eq!(
+            column(table(&tables, "users"), "created_at").default,
+            "CURRENT_TIMESTAMP"
+        );
+    }
+
+    #[test]
+    fn mysqldump_keys_and_constraints() {
+        let tables = parse_ddl(&DatabaseType::Mysql, MYSQLDUMP).unwrap();
+        let posts = table(&tables, "posts");
+
+        assert!(index(posts, "PRIMARY").is_unique);
+        assert_eq!(
+            index(posts, "idx_posts_user_title").columns,
+            ["user_id", "title"]
+        );
+        assert!(index(table(&tables, "users"), "users_email_key").is_unique);
+
+        let foreign_keys = posts.foreign_keys();
+        assert_eq!(foreign_keys.len(), 1);
+        assert_eq!(foreign_keys[0].name, "posts_user_id_fkey");
+        assert_eq!(foreign_keys[0].foreign_column.table_name, "users");
+
+        // idx_posts_user_title이 user_id로 시작하므로, 외래키용 인덱스가 따로 생기지 않습니다.
+        assert_eq!(posts.indexes.len(), 2);
+    }
+
+    #[test]
+    fn mysql_alter_table_add_constraint_and_index() {
+        let ddl = "
+            CREATE TABLE `a` (`id` int NOT NULL, `b_id` int NOT NULL);
+            CREATE TABLE `b` (`id` int NOT NULL);
+            ALTER TABLE `a` ADD CONSTRAINT `a_b_fk` FOREIGN KEY (`b_id`) REFERENCES `b` (`id`);
+            CREATE UNIQUE INDEX `b_id_key` ON `b` (`id`);
+        ";
+        let tables = parse_ddl(&DatabaseType::Mysql, ddl).unwrap();
+        let a = table(&tables, "a");
+
+        assert_eq!(a.foreign_keys()[0].name, "a_b_fk");
+        // MySQL은 외래키 컬럼에 인덱스가 없다면 외래키 이름으로 인덱스를 만듭니다.
+        assert_eq!(index(a, "a_b_fk").columns, ["b_id"]);
+        assert!(index(table(&tables, "b"), "b_id_key").is_unique);
+    }
+}
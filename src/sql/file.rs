@@ -0,0 +1,51 @@
+use crate::config::DatabaseType;
+
+use super::{ddl, spec, Database, SchemaSource, Table};
+
+// DDL 파일이나 명세 파일에서 읽어온 테이블 목록
+#[derive(Debug)]
+pub struct FileSource {
+    pub path: String,
+    pub table_list: Vec<Table>,
+}
+
+impl FileSource {
+    pub fn open(database_type: &DatabaseType, path: &str) -> anyhow::Result<Self> {
+        let table_list = if spec::is_spec_file(path) {
+            spec::read_spec_file(database_type, path)?
+        } else {
+            ddl::read_ddl_file(database_type, path)?
+        };
+
+        Ok(Self {
+            path: path.to_string(),
+            table_list,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SchemaSource for FileSource {
+    async fn get_table_list(&self) -> anyhow::Result<Vec<String>> {
+        Ok(self
+            .table_list
+            .iter()
+            .map(|table| table.name.clone())
+            .collect())
+    }
+
+    async fn describe_table(&self, table_name: &str) -> anyhow::Result<Table> {
+        self.table_list
+            .iter()
+            .find(|table| table.name == table_name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("table not found: {table_name}"))
+    }
+
+    async fn describe_database(&self) -> anyhow::Result<Database> {
+        Ok(Database {
+            name: self.path.clone(),
+            version: String::new(),
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use tokio::{sync::Mutex, time::Instant};
+
+// 초당 쿼리 수를 제한합니다.
+// 쿼리마다 일정한 간격의 실행 슬롯을 예약하고, 슬롯이 올 때까지 기다립니다.
+pub struct RateLimiter {
+    interval: Option<Duration>,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(max_queries_per_second: Option<u32>) -> Self {
+        let interval = max_queries_per_second
+            .filter(|count| *count > 0)
+            .map(|count| Duration::from_secs(1) / count);
+
+        Self {
+            interval,
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    // query_count개의 쿼리를 실행할 수 있을 때까지 기다립니다.
+    pub async fn acquire(&self, query_count: u32) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + interval * query_count;
+
+            slot
+        };
+
+        tokio::time::sleep_until(slot).await;
+    }
+}
@@ -1,61 +1,270 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{DatabaseType, SessionOptions, TlsOptions};
+
+pub mod ddl;
+pub mod file;
+pub mod limit;
 pub mod mysql;
+pub mod option_file;
 pub mod postgres;
+pub mod secret;
+pub mod service;
+pub mod spec;
+pub mod tunnel;
+
+// 스키마 정보를 조회할 수 있는 대상입니다.
+// 데이터베이스 엔진이나 스냅샷 파일 등 새로운 대상을 추가하려면 이 트레잇을 구현하고 connect에 등록합니다.
+// 비교 대상은 테이블(뷰 포함)과 그 컬럼, 인덱스, 외래키뿐입니다.
+// 시퀀스, enum 타입처럼 테이블에 속하지 않는 객체는 아직 조회하지 않습니다.
+#[async_trait::async_trait]
+pub trait SchemaSource: Send + Sync {
+    // 비교 대상 테이블 이름 목록 (뷰 포함)
+    async fn get_table_list(&self) -> anyhow::Result<Vec<String>>;
+
+    // 테이블의 컬럼, 인덱스, 제약조건 정보
+    async fn describe_table(&self, table_name: &str) -> anyhow::Result<Table>;
+
+    // 데이터베이스 자체의 정보 (이름, 버전). 비교에는 사용하지 않고, 연결을 확인할 때 보여줍니다.
+    async fn describe_database(&self) -> anyhow::Result<Database>;
 
-#[derive(Debug)]
-pub enum ConnectionPool {
-    Postgres(sqlx::Pool<sqlx::Postgres>),
-    MySQL(sqlx::Pool<sqlx::MySql>),
+    // 조회를 시작한 시점 이후로 스키마가 변경되었는지 확인합니다.
+    // 파일처럼 변경될 수 없는 대상은 기본 구현을 사용합니다.
+    async fn schema_changed(&self) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+}
+
+// 데이터베이스 연결 옵션
+#[derive(Debug, Clone)]
+pub struct ConnectOptions {
+    // 커넥션 풀의 최대 커넥션 수. 테이블 정보를 동시에 조회하는 개수이기도 합니다.
+    pub pool_size: u32,
+    pub session: SessionOptions,
+    // URL의 비밀번호 대신 사용할 비밀번호 (password_command 등으로 얻은 값)
+    pub password: Option<String>,
+    pub tls: TlsOptions,
+}
+
+impl Default for ConnectOptions {
+    fn default() -> Self {
+        Self {
+            pool_size: 5,
+            session: SessionOptions::default(),
+            password: None,
+            tls: TlsOptions::default(),
+        }
+    }
+}
+
+// 연결 문자열에 맞는 SchemaSource를 생성합니다.
+// "file:schema.sql" 형태라면 데이터베이스 대신 DDL 파일을, 확장자가 .yaml, .yml, .toml, .json 이라면 명세 파일을 읽습니다.
+pub async fn connect(
+    database_type: &DatabaseType,
+    connection_url: &str,
+    options: &ConnectOptions,
+) -> anyhow::Result<Box<dyn SchemaSource>> {
+    if let Some(path) = connection_url.strip_prefix("file:") {
+        let path = path.strip_prefix("//").unwrap_or(path);
+
+        return Ok(Box::new(file::FileSource::open(database_type, path)?));
+    }
+
+    match database_type {
+        DatabaseType::Postgres => Ok(Box::new(
+            postgres::PostgresSource::open(connection_url, options).await?,
+        )),
+        DatabaseType::Mysql => Ok(Box::new(
+            mysql::MySqlSource::open(connection_url, options).await?,
+        )),
+    }
+}
+
+// 연결할 수 있는지만 확인하고, 데이터베이스 이름과 버전을 반환합니다. (init, config validate)
+// connect와 달리 스냅샷을 만들지 않습니다. 파일이라면 읽을 수 있는지 확인합니다.
+pub async fn check_connection(
+    database_type: &DatabaseType,
+    connection_url: &str,
+    options: &ConnectOptions,
+) -> anyhow::Result<Database> {
+    if connection_url.starts_with("file:") {
+        return connect(database_type, connection_url, options)
+            .await?
+            .describe_database()
+            .await;
+    }
+
+    // 커넥션 풀은 연결에 실패하면 재시도하다가 시간 초과로만 알려주므로, 커넥션 하나로 바로 확인합니다.
+    match database_type {
+        DatabaseType::Postgres => postgres::check_connection(connection_url, options).await,
+        DatabaseType::Mysql => mysql::check_connection(connection_url, options).await,
+    }
 }
 
-#[derive(Debug, Default)]
+// 전체 테이블 정보를 조회해서 이름을 키로 하는 맵으로 반환합니다.
+// 최대 concurrency개의 테이블을 동시에 조회합니다.
+// on_progress는 (조회가 끝난 테이블 수, 전체 테이블 수)로 호출됩니다.
+pub async fn get_table_map(
+    source: &dyn SchemaSource,
+    concurrency: usize,
+    mut on_progress: impl FnMut(usize, usize),
+) -> anyhow::Result<HashMap<String, Table>> {
+    use futures::{StreamExt, TryStreamExt};
+
+    let table_list = match source.get_table_list().await {
+        Ok(list) => list,
+        Err(error) => {
+            return Err(anyhow::anyhow!("failed to get table list: {:?}", error));
+        }
+    };
+
+    let table_count = table_list.len();
+
+    on_progress(0, table_count);
+
+    let mut tables = futures::stream::iter(table_list)
+        .map(|table_name| async move {
+            match source.describe_table(&table_name).await {
+                Ok(table) => Ok((table_name, table)),
+                Err(error) => Err(anyhow::anyhow!(
+                    "failed to describe table [{table_name}]: {:?}",
+                    error
+                )),
+            }
+        })
+        .buffer_unordered(concurrency.max(1));
+
+    let mut table_map = HashMap::new();
+
+    while let Some((table_name, table)) = tables.try_next().await? {
+        table_map.insert(table_name, table);
+
+        on_progress(table_map.len(), table_count);
+    }
+
+    Ok(table_map)
+}
+
+// 데이터베이스 자체의 정보. 스키마 객체는 담지 않습니다.
+#[derive(Debug, Default, Clone)]
+pub struct Database {
+    pub name: String,
+    pub version: String,
+}
+
+// 비교 대상이 되는 컬럼/인덱스/외래키의 속성
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Property {
+    DataType,
+    Default,
+    Nullable,
+    Comment,
+    AutoIncrement,
+    Columns,
+    Predicate,
+    Unique,
+    References,
+    Name,
+}
+
+#[derive(Debug, Default, Clone)]
 pub struct Column {
     pub name: String,
     pub data_type: String,
     pub default: String,
     pub nullable: bool,
     pub comment: String,
-    pub is_auto_increment: bool, // MYSQL Only
+    pub is_auto_increment: bool,  // MYSQL Only
+    pub unchecked: Vec<Property>, // 명세 파일에 정의되지 않아서 비교하지 않는 속성
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
 pub struct Index {
-    pub name: String,
+    pub name: String, // 명세 파일에서는 비어있을 수 있습니다. 이 경우 컬럼 목록으로 비교합니다.
     pub columns: Vec<String>,
     pub predicate: String,
     pub is_unique: bool,
+    pub unchecked: Vec<Property>, // 명세 파일에 정의되지 않아서 비교하지 않는 속성
 }
 
-#[derive(Debug, PartialEq)]
+impl Index {
+    // 보고서에 표시할 이름
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() {
+            format!("({})", self.columns.join(", "))
+        } else {
+            self.name.clone()
+        }
+    }
+
+    // 이름을 제외한 정의(컬럼, UNIQUE 여부, 조건)가 같은지 확인합니다.
+    // 어느 한쪽이라도 비교하지 않는 속성은 무시합니다.
+    pub fn has_same_definition(&self, other: &Index) -> bool {
+        let is_checked =
+            |property| !self.unchecked.contains(&property) && !other.unchecked.contains(&property);
+
+        self.columns == other.columns
+            && (!is_checked(Property::Unique) || self.is_unique == other.is_unique)
+            && (!is_checked(Property::Predicate) || self.predicate == other.predicate)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ForeignKey {
     pub name: String,
     pub column: Vec<String>,
     pub foreign_column: SelectColumn,
 }
 
+impl ForeignKey {
+    // 보고서에 표시할 이름
+    pub fn display_name(&self) -> String {
+        if self.name.is_empty() {
+            format!(
+                "({}) -> {}.{}",
+                self.column.join(", "),
+                self.foreign_column.table_name,
+                self.foreign_column.column_name
+            )
+        } else {
+            self.name.clone()
+        }
+    }
+
+    // 이름을 제외한 정의(컬럼, 참조 컬럼)가 같은지 확인합니다.
+    pub fn has_same_definition(&self, other: &ForeignKey) -> bool {
+        self.column == other.column && self.foreign_column == other.foreign_column
+    }
+}
+
 impl From<ForeignKey> for Constraint {
     fn from(fk: ForeignKey) -> Self {
         Constraint::ForeignKey(fk)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Constraint {
     ForeignKey(ForeignKey),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SelectColumn {
     pub table_name: String,
     pub column_name: String,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Table {
     pub name: String,
     pub comment: String,
     pub columns: Vec<Column>,
     pub indexes: Vec<Index>,
     pub constraints: Vec<Constraint>,
+    pub unchecked: Vec<Property>, // 명세 파일에 정의되지 않아서 비교하지 않는 속성
 }
 
 // foreign key 관련 메서드
@@ -77,4 +286,193 @@ impl Table {
             .find(|fk| fk.name == key_name)
             .cloned()
     }
+
+    // 이름이 같은 외래키를 먼저 찾고, 없으면 정의가 같은 외래키를 찾습니다.
+    // 자동 생성된 이름(posts_ibfk_2 등)은 환경마다 다를 수 있기 때문입니다.
+    // other는 foreign_key가 속한 테이블이며, other에 이름이 같은 짝이 있는 외래키는 후보에서 제외합니다.
+    // 이름이 없는 외래키(명세 파일)는 컬럼으로도 찾습니다.
+    pub fn find_foreign_key(&self, foreign_key: &ForeignKey, other: &Table) -> Option<&ForeignKey> {
+        if !foreign_key.name.is_empty() {
+            let same_name: Vec<&ForeignKey> = self
+                .foreign_keys()
+                .into_iter()
+                .filter(|fk| fk.name == foreign_key.name)
+                .collect();
+
+            // 여러 컬럼으로 구성된 외래키는 컬럼마다 항목이 있으므로, 컬럼이 같은 항목을 우선합니다.
+            if let Some(found) = same_name
+                .iter()
+                .find(|fk| fk.column == foreign_key.column)
+                .or(same_name.first())
+            {
+                return Some(found);
+            }
+        }
+
+        let candidates: Vec<&ForeignKey> = self
+            .foreign_keys()
+            .into_iter()
+            .filter(|fk| other.find_foreign_key_by_key_name(&fk.name).is_none())
+            .collect();
+
+        if let Some(found) = candidates
+            .iter()
+            .find(|fk| fk.has_same_definition(foreign_key))
+        {
+            return Some(found);
+        }
+
+        if foreign_key.name.is_empty() {
+            return candidates
+                .into_iter()
+                .find(|fk| fk.column == foreign_key.column);
+        }
+
+        None
+    }
+
+    // 이름이 같은 인덱스를 먼저 찾고, 없으면 정의가 같은 인덱스를 찾습니다.
+    // 자동 생성된 이름(idx_1234 등)은 환경마다 다를 수 있기 때문입니다.
+    // other는 index가 속한 테이블이며, other에 이름이 같은 짝이 있는 인덱스는 후보에서 제외합니다.
+    // 이름이 없는 인덱스(명세 파일)는 컬럼 목록으로도 찾습니다.
+    pub fn find_index(&self, index: &Index, other: &Table) -> Option<&Index> {
+        if !index.name.is_empty() {
+            if let Some(found) = self.indexes.iter().find(|i| i.name == index.name) {
+                return Some(found);
+            }
+        }
+
+        let candidates: Vec<&Index> = self
+            .indexes
+            .iter()
+            .filter(|i| i.name.is_empty() || !other.indexes.iter().any(|o| o.name == i.name))
+            .collect();
+
+        if let Some(found) = candidates.iter().find(|i| i.has_same_definition(index)) {
+            return Some(found);
+        }
+
+        if index.name.is_empty() {
+            return candidates.into_iter().find(|i| i.columns == index.columns);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(name: &str, columns: &[&str], is_unique: bool) -> Index {
+        Index {
+            name: name.to_string(),
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            is_unique,
+            ..Default::default()
+        }
+    }
+
+    fn foreign_key(name: &str, column: &str, table_name: &str) -> ForeignKey {
+        ForeignKey {
+            name: name.to_string(),
+            column: vec![column.to_string()],
+            foreign_column: SelectColumn {
+                table_name: table_name.to_string(),
+                column_name: "id".to_string(),
+            },
+        }
+    }
+
+    fn table(indexes: Vec<Index>, foreign_keys: Vec<ForeignKey>) -> Table {
+        Table {
+            name: "posts".to_string(),
+            indexes,
+            constraints: foreign_keys.into_iter().map(Constraint::from).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn index_is_found_by_definition_when_names_differ() {
+        let base = table(vec![index("idx_1234", &["user_id"], false)], vec![]);
+        let target = table(
+            vec![
+                index("idx_9876", &["user_id"], false),
+                index("idx_title", &["title"], false),
+            ],
+            vec![],
+        );
+
+        let found = target.find_index(&base.indexes[0], &base);
+        assert_eq!(found.map(|index| index.name.as_str()), Some("idx_9876"));
+
+        // UNIQUE 여부가 다르면 정의가 다른 인덱스입니다.
+        let target = table(vec![index("idx_9876", &["user_id"], true)], vec![]);
+        assert!(target.find_index(&base.indexes[0], &base).is_none());
+    }
+
+    #[test]
+    fn index_with_same_name_pair_is_not_a_candidate() {
+        // target의 idx_a는 base의 idx_a와 이름으로 짝을 이루므로, idx_b의 짝이 될 수 없습니다.
+        let base = table(
+            vec![
+                index("idx_a", &["user_id"], false),
+                index("idx_b", &["user_id"], false),
+            ],
+            vec![],
+        );
+        let target = table(vec![index("idx_a", &["user_id"], false)], vec![]);
+
+        assert!(target.find_index(&base.indexes[1], &base).is_none());
+    }
+
+    #[test]
+    fn unnamed_index_is_found_by_columns() {
+        let mut spec_index = index("", &["user_id"], false);
+        spec_index.unchecked = vec![Property::Unique];
+        let base = table(vec![spec_index], vec![]);
+        let target = table(vec![index("idx_user_id", &["user_id"], true)], vec![]);
+
+        let found = target.find_index(&base.indexes[0], &base);
+        assert_eq!(found.map(|index| index.name.as_str()), Some("idx_user_id"));
+    }
+
+    #[test]
+    fn foreign_key_is_found_by_definition_when_names_differ() {
+        let base = table(
+            vec![],
+            vec![foreign_key("posts_ibfk_1", "user_id", "users")],
+        );
+        let target = table(
+            vec![],
+            vec![
+                foreign_key("posts_ibfk_2", "user_id", "users"),
+                foreign_key("posts_ibfk_1", "editor_id", "users"),
+            ],
+        );
+
+        let found = target.find_foreign_key(base.foreign_keys()[0], &base);
+        // 이름이 같은 외래키가 있으면 정의가 달라도 이름을 우선합니다.
+        assert_eq!(
+            found.map(|fk| fk.column.clone()),
+            Some(vec!["editor_id".to_string()])
+        );
+
+        let target = table(
+            vec![],
+            vec![foreign_key("posts_ibfk_2", "user_id", "users")],
+        );
+        let found = target.find_foreign_key(base.foreign_keys()[0], &base);
+        assert_eq!(found.map(|fk| fk.name.as_str()), Some("posts_ibfk_2"));
+
+        // 참조하는 테이블이 다르면 정의가 다른 외래키입니다.
+        let target = table(
+            vec![],
+            vec![foreign_key("posts_ibfk_2", "user_id", "accounts")],
+        );
+        assert!(target
+            .find_foreign_key(base.foreign_keys()[0], &base)
+            .is_none());
+    }
 }
@@ -0,0 +1,269 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+// MySQL 옵션 파일(my.cnf)과 pg_service.conf처럼 [group] 아래에 key=value를 적는 INI 형식 파일입니다.
+// 그룹 이름과 키는 소문자로, 키의 '-'는 '_'로 바꿔서 저장합니다.
+#[derive(Debug, Default)]
+pub struct OptionFile {
+    groups: HashMap<String, HashMap<String, String>>,
+}
+
+// !include가 다른 파일을 포함할 수 있는 최대 깊이. 서로를 포함하는 파일 때문에 멈추지 않도록 제한합니다.
+const MAX_INCLUDE_DEPTH: usize = 10;
+
+impl OptionFile {
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let mut option_file = Self::default();
+        option_file.read_file(path, 0)?;
+
+        Ok(option_file)
+    }
+
+    // 여러 파일을 차례로 읽어서 합칩니다. 뒤에 읽은 파일의 값이 우선하며, 없는 파일은 건너뜁니다.
+    pub fn read_all(paths: &[PathBuf]) -> anyhow::Result<Self> {
+        let mut option_file = Self::default();
+
+        for path in paths.iter().filter(|path| path.is_file()) {
+            option_file.read_file(path, 0)?;
+        }
+
+        Ok(option_file)
+    }
+
+    fn read_file(&mut self, path: &Path, depth: usize) -> anyhow::Result<()> {
+        if depth > MAX_INCLUDE_DEPTH {
+            return Err(anyhow::anyhow!(
+                "option file {path:?} is included more than {MAX_INCLUDE_DEPTH} levels deep"
+            ));
+        }
+
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) => {
+                return Err(anyhow::anyhow!(
+                    "failed to read option file {path:?}: {error}"
+                ));
+            }
+        };
+
+        // !include의 상대 경로는 이 파일이 있는 디렉터리를 기준으로 합니다.
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+
+        self.parse_lines(&text, |option_file, directive, argument| {
+            let argument = base_dir.join(argument);
+
+            match directive {
+                "include" => option_file.read_file(&argument, depth + 1),
+                // MySQL처럼 디렉터리의 .cnf 파일을 이름 순서로 읽습니다.
+                "includedir" => {
+                    let mut paths: Vec<PathBuf> = match std::fs::read_dir(&argument) {
+                        Ok(entries) => entries
+                            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                            .filter(|path| {
+                                path.extension().is_some_and(|extension| extension == "cnf")
+                            })
+                            .collect(),
+                        Err(error) => {
+                            return Err(anyhow::anyhow!(
+                                "failed to read option directory {argument:?}: {error}"
+                            ));
+                        }
+                    };
+                    paths.sort();
+
+                    paths
+                        .iter()
+                        .try_for_each(|path| option_file.read_file(path, depth + 1))
+                }
+                _ => Ok(()),
+            }
+        })
+    }
+
+    // 파일 없이 내용만 읽습니다. !include 같은 지시문은 무시합니다.
+    pub fn parse(text: &str) -> Self {
+        let mut option_file = Self::default();
+        _ = option_file.parse_lines(text, |_, _, _| Ok(()));
+
+        option_file
+    }
+
+    // on_directive는 !include 같은 지시문을 (지시문, 인자)로 받습니다.
+    fn parse_lines(
+        &mut self,
+        text: &str,
+        mut on_directive: impl FnMut(&mut Self, &str, &str) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        let mut current_group = None;
+
+        for line in text.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            if let Some(directive) = line.strip_prefix('!') {
+                let (directive, argument) = directive
+                    .split_once(char::is_whitespace)
+                    .unwrap_or((directive, ""));
+
+                on_directive(self, directive, argument.trim())?;
+                continue;
+            }
+
+            if let Some(group) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                let group = group.trim().to_lowercase();
+                self.groups.entry(group.clone()).or_default();
+                current_group = Some(group);
+                continue;
+            }
+
+            let Some(group) = &current_group else {
+                continue;
+            };
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), unquote(value.trim())),
+                None => (line, String::new()),
+            };
+
+            self.groups
+                .entry(group.clone())
+                .or_default()
+                .insert(key.to_lowercase().replace('-', "_"), value);
+        }
+
+        Ok(())
+    }
+
+    pub fn group(&self, name: &str) -> Option<&HashMap<String, String>> {
+        self.groups.get(&name.to_lowercase())
+    }
+
+    // 여러 그룹에서 차례로 찾습니다. (예: ["client", "mysql"])
+    pub fn get(&self, group_names: &[&str], key: &str) -> Option<&str> {
+        group_names
+            .iter()
+            .filter_map(|group_name| self.group(group_name))
+            .find_map(|group| group.get(key))
+            .map(String::as_str)
+    }
+}
+
+fn unquote(value: &str) -> String {
+    for quote in ['"', '\''] {
+        if let Some(value) = value
+            .strip_prefix(quote)
+            .and_then(|value| value.strip_suffix(quote))
+        {
+            return value.to_string();
+        }
+    }
+
+    value.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "magiceye-option-file-test-{}-{name}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn comments_and_keys() {
+        let option_file = OptionFile::parse(
+            "# comment\n; comment\nhost = ignored\n\n[Client]\n  Host = db.internal  \nssl-mode=REQUIRED\nskip-ssl\n",
+        );
+
+        // 그룹 밖의 값은 무시하고, 그룹 이름과 키는 소문자로, 키의 -는 _로 바꿉니다.
+        let client = option_file.group("client").unwrap();
+        assert_eq!(client.len(), 3);
+        assert_eq!(client["host"], "db.internal");
+        assert_eq!(client["ssl_mode"], "REQUIRED");
+        assert_eq!(client["skip_ssl"], "");
+    }
+
+    #[test]
+    fn quoted_values() {
+        let option_file = OptionFile::parse(
+            "[client]\npassword = \"p@ss word\"\nuser='app#1'\nhost=\"unterminated\n",
+        );
+
+        assert_eq!(option_file.get(&["client"], "password"), Some("p@ss word"));
+        assert_eq!(option_file.get(&["client"], "user"), Some("app#1"));
+        assert_eq!(option_file.get(&["client"], "host"), Some("\"unterminated"));
+    }
+
+    #[test]
+    fn missing_group() {
+        let option_file = OptionFile::parse("[client]\nuser=app\n\n[client_prod]\nuser=prod\n");
+
+        assert!(option_file.group("client_dev").is_none());
+        assert_eq!(option_file.get(&["client_dev"], "user"), None);
+        // 앞의 그룹에 없다면 다음 그룹에서 찾습니다.
+        assert_eq!(
+            option_file.get(&["client_dev", "client"], "user"),
+            Some("app")
+        );
+        assert_eq!(
+            option_file.get(&["client_prod", "client"], "user"),
+            Some("prod")
+        );
+    }
+
+    #[test]
+    fn include_and_includedir() {
+        let dir = temp_dir("include");
+        std::fs::create_dir_all(dir.join("conf.d")).unwrap();
+
+        std::fs::write(
+            dir.join("my.cnf"),
+            "[client]\nuser=app\nhost=main\n!include extra.cnf\n!includedir conf.d\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("extra.cnf"), "[client]\nhost=extra\n").unwrap();
+        std::fs::write(dir.join("conf.d/1.cnf"), "[client_prod]\nhost=prod-1\n").unwrap();
+        std::fs::write(dir.join("conf.d/2.cnf"), "[client_prod]\nhost=prod-2\n").unwrap();
+        std::fs::write(dir.join("conf.d/ignored.txt"), "[client_prod]\nhost=txt\n").unwrap();
+
+        let option_file = OptionFile::read(&dir.join("my.cnf")).unwrap();
+
+        assert_eq!(option_file.get(&["client"], "user"), Some("app"));
+        assert_eq!(option_file.get(&["client"], "host"), Some("extra"));
+        assert_eq!(option_file.get(&["client_prod"], "host"), Some("prod-2"));
+
+        // parse는 파일을 읽지 않으므로 지시문을 무시합니다.
+        let option_file = OptionFile::parse("!include extra.cnf\n[client]\nuser=app\n");
+        assert_eq!(option_file.get(&["client"], "user"), Some("app"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn include_loop_and_missing_include() {
+        let dir = temp_dir("loop");
+
+        std::fs::write(dir.join("a.cnf"), "!include b.cnf\n").unwrap();
+        std::fs::write(dir.join("b.cnf"), "!include a.cnf\n").unwrap();
+        std::fs::write(dir.join("c.cnf"), "!include missing.cnf\n").unwrap();
+
+        assert!(OptionFile::read(&dir.join("a.cnf")).is_err());
+        assert!(OptionFile::read(&dir.join("c.cnf")).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
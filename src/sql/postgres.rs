@@ -1,25 +1,81 @@
-use sqlx::{postgres::PgPoolOptions, Pool, Postgres};
+use std::sync::Mutex;
 
+use std::str::FromStr;
+
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions, PgSslMode},
+    ConnectOptions as _, Connection as _, PgConnection, Pool, Postgres, Transaction,
+};
+
+use crate::config::TlsMode;
 use crate::sql::{Column, ForeignKey, Index};
 
-use super::{ConnectionPool, Table};
+use super::{limit::RateLimiter, ConnectOptions, Database, SchemaSource, Table};
+
+// 모든 커넥션은 읽기 전용이며, statement_timeout과 lock_timeout을 넘는 쿼리는 실패합니다.
+// URL에 비밀번호가 없다면 PGPASSWORD 환경변수와 .pgpass 파일을 사용합니다.
+pub fn get_connect_options(
+    connection_url: &str,
+    connect_options: &ConnectOptions,
+) -> anyhow::Result<PgConnectOptions> {
+    let session = &connect_options.session;
+
+    let mut options = PgConnectOptions::from_str(connection_url)?
+        .application_name(&session.application_name)
+        .options([
+            ("default_transaction_read_only", "on".to_string()),
+            (
+                "statement_timeout",
+                session.statement_timeout_ms.to_string(),
+            ),
+            ("lock_timeout", session.lock_timeout_ms.to_string()),
+        ]);
+
+    if let Some(password) = &connect_options.password {
+        options = options.password(password);
+    }
+
+    // TLS 설정은 URL의 sslmode 등보다 우선합니다.
+    let tls = &connect_options.tls;
+
+    if let Some(mode) = tls.mode {
+        options = options.ssl_mode(match mode {
+            TlsMode::Disable => PgSslMode::Disable,
+            TlsMode::Prefer => PgSslMode::Prefer,
+            TlsMode::Require => PgSslMode::Require,
+            TlsMode::VerifyCa => PgSslMode::VerifyCa,
+            TlsMode::VerifyFull => PgSslMode::VerifyFull,
+        });
+    }
+
+    if let Some(root_cert) = &tls.root_cert {
+        options = options.ssl_root_cert(root_cert);
+    }
 
-pub async fn ping(pool: &Pool<Postgres>) -> Result<(), sqlx::Error> {
-    pool.acquire().await?;
+    if let Some(client_cert) = &tls.client_cert {
+        options = options.ssl_client_cert(client_cert);
+    }
 
-    Ok(())
+    if let Some(client_key) = &tls.client_key {
+        options = options.ssl_client_key(client_key);
+    }
+
+    Ok(options)
 }
 
-pub async fn get_connection_pool(connection_url: &str) -> anyhow::Result<ConnectionPool> {
+pub async fn get_connection_pool(
+    connection_url: &str,
+    connect_options: &ConnectOptions,
+) -> anyhow::Result<Pool<Postgres>> {
     let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(connection_url)
+        .max_connections(connect_options.pool_size)
+        .connect_with(get_connect_options(connection_url, connect_options)?)
         .await?;
 
-    Ok(ConnectionPool::Postgres(pool))
+    Ok(pool)
 }
 
-pub async fn get_table_list(pool: &Pool<Postgres>) -> anyhow::Result<Vec<String>> {
+pub async fn get_table_list(connection: &mut PgConnection) -> anyhow::Result<Vec<String>> {
     let table_list = sqlx::query_as::<_, (String,)>(
         r#"
         SELECT table_name
@@ -27,7 +83,7 @@ pub async fn get_table_list(pool: &Pool<Postgres>) -> anyhow::Result<Vec<String>
         WHERE table_schema = 'public'
     "#,
     )
-    .fetch_all(pool)
+    .fetch_all(&mut *connection)
     .await?;
 
     Ok(table_list
@@ -43,7 +99,10 @@ fn format_type(data_type: &str, character_maximum_length: i32) -> String {
     }
 }
 
-pub async fn describe_table(pool: &Pool<Postgres>, table_name: &str) -> anyhow::Result<Table> {
+pub async fn describe_table(
+    connection: &mut PgConnection,
+    table_name: &str,
+) -> anyhow::Result<Table> {
     log::debug!("describe table: {table_name}");
 
     // 1. 컬럼 리스트 정보 조회
@@ -67,10 +126,11 @@ pub async fn describe_table(pool: &Pool<Postgres>, table_name: &str) -> anyhow::
                 WHERE relname = c.table_name
             )
         WHERE c.table_name = $1
+        ORDER BY c.ordinal_position
     "#,
     )
     .bind(table_name)
-    .fetch_all(pool)
+    .fetch_all(&mut *connection)
     .await?;
 
     let columns = query_result
@@ -98,7 +158,7 @@ pub async fn describe_table(pool: &Pool<Postgres>, table_name: &str) -> anyhow::
         "#,
     )
     .bind(table_name)
-    .fetch_all(pool)
+    .fetch_all(&mut *connection)
     .await?;
 
     let table_comment = query_result
@@ -134,7 +194,7 @@ pub async fn describe_table(pool: &Pool<Postgres>, table_name: &str) -> anyhow::
         "#,
     )
     .bind(table_name)
-    .fetch_all(pool)
+    .fetch_all(&mut *connection)
     .await?;
 
     let indexes = query_result
@@ -144,6 +204,7 @@ pub async fn describe_table(pool: &Pool<Postgres>, table_name: &str) -> anyhow::
             columns: columns.split(',').map(|s| s.to_string()).collect(),
             is_unique,
             predicate,
+            ..Default::default()
         })
         .collect();
 
@@ -170,7 +231,7 @@ pub async fn describe_table(pool: &Pool<Postgres>, table_name: &str) -> anyhow::
         "#,
     )
     .bind(table_name)
-    .fetch_all(pool)
+    .fetch_all(&mut *connection)
     .await?;
 
     for (name, column, foreign_table_name, foreign_column_name) in query_result {
@@ -190,6 +251,7 @@ pub async fn describe_table(pool: &Pool<Postgres>, table_name: &str) -> anyhow::
     let table = Table {
         name: table_name.to_string(),
         comment: table_comment,
+        unchecked: vec![],
         columns,
         indexes,
         constraints,
@@ -197,3 +259,257 @@ pub async fn describe_table(pool: &Pool<Postgres>, table_name: &str) -> anyhow::
 
     Ok(table)
 }
+
+// 커넥션 하나로 연결할 수 있는지 확인하고, 데이터베이스 이름과 버전을 반환합니다.
+pub async fn check_connection(
+    connection_url: &str,
+    options: &ConnectOptions,
+) -> anyhow::Result<Database> {
+    let mut connection = get_connect_options(connection_url, options)?
+        .connect()
+        .await?;
+
+    connection.ping().await?;
+
+    let database = describe_database(&mut connection).await;
+    _ = connection.close().await;
+
+    database
+}
+
+pub async fn describe_database(connection: &mut PgConnection) -> anyhow::Result<Database> {
+    let (name, version) =
+        sqlx::query_as::<_, (String, String)>("SELECT current_database(), version()")
+            .fetch_one(&mut *connection)
+            .await?;
+
+    Ok(Database { name, version })
+}
+
+// 카탈로그 상태를 요약한 값입니다. 같은 스키마라면 같은 값이 나옵니다.
+// pg_get_indexdef 같은 함수는 트랜잭션 스냅샷이 아닌 최신 카탈로그를 읽으므로, 카탈로그 컬럼만 사용합니다.
+pub async fn get_fingerprint(connection: &mut PgConnection) -> anyhow::Result<String> {
+    let (fingerprint,) = sqlx::query_as::<_, (String,)>(
+        r#"
+            SELECT md5(coalesce(string_agg(definition, E'\n' ORDER BY definition), ''))
+            FROM (
+                SELECT concat_ws(' ', c.relname, c.relkind) AS definition
+                FROM pg_catalog.pg_class c
+                JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+                WHERE n.nspname = 'public'
+
+                UNION ALL
+
+                SELECT concat_ws(' ', c.relname, a.attname, a.atttypid, a.atttypmod, a.attnotnull, d.adbin::text)
+                FROM pg_catalog.pg_attribute a
+                JOIN pg_catalog.pg_class c ON c.oid = a.attrelid
+                JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+                LEFT JOIN pg_catalog.pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+                WHERE n.nspname = 'public' AND a.attnum > 0 AND NOT a.attisdropped
+
+                UNION ALL
+
+                SELECT concat_ws(' ', i.relname, ix.indkey::text, ix.indisunique, ix.indpred::text)
+                FROM pg_catalog.pg_index ix
+                JOIN pg_catalog.pg_class i ON i.oid = ix.indexrelid
+                JOIN pg_catalog.pg_namespace n ON n.oid = i.relnamespace
+                WHERE n.nspname = 'public'
+
+                UNION ALL
+
+                SELECT concat_ws(' ', c.relname, con.conname, con.contype, con.conkey::text, con.confrelid, con.confkey::text)
+                FROM pg_catalog.pg_constraint con
+                JOIN pg_catalog.pg_class c ON c.oid = con.conrelid
+                JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+                WHERE n.nspname = 'public'
+
+                UNION ALL
+
+                SELECT concat_ws(' ', c.relname, d.objsubid, d.description)
+                FROM pg_catalog.pg_description d
+                JOIN pg_catalog.pg_class c ON c.oid = d.objoid AND d.classoid = 'pg_catalog.pg_class'::regclass
+                JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+                WHERE n.nspname = 'public'
+            ) definitions
+        "#,
+    )
+    .fetch_one(&mut *connection)
+    .await?;
+
+    Ok(fingerprint)
+}
+
+// 모든 조회는 하나의 REPEATABLE READ 스냅샷을 공유합니다.
+// 스냅샷을 export한 트랜잭션이 조회가 끝날 때까지 열려 있어야 하므로, 풀과 별개의 커넥션으로 유지합니다.
+pub struct PostgresSource {
+    pub pool: Pool<Postgres>,
+    // 드롭되면 트랜잭션과 함께 스냅샷도 사라집니다.
+    _snapshot_connection: Mutex<PgConnection>,
+    snapshot_id: String,
+    snapshot_fingerprint: String,
+    rate_limiter: RateLimiter,
+}
+
+// describe_table이 실행하는 카탈로그 쿼리 수
+const DESCRIBE_TABLE_QUERY_COUNT: u32 = 4;
+
+impl PostgresSource {
+    pub async fn open(connection_url: &str, options: &ConnectOptions) -> anyhow::Result<Self> {
+        let pool = get_connection_pool(connection_url, options).await?;
+
+        let mut snapshot_connection = get_connect_options(connection_url, options)?
+            .connect()
+            .await?;
+
+        sqlx::query("BEGIN ISOLATION LEVEL REPEATABLE READ READ ONLY")
+            .execute(&mut snapshot_connection)
+            .await?;
+
+        let (snapshot_id,) = sqlx::query_as::<_, (String,)>("SELECT pg_export_snapshot()")
+            .fetch_one(&mut snapshot_connection)
+            .await?;
+
+        let snapshot_fingerprint = get_fingerprint(&mut snapshot_connection).await?;
+
+        Ok(Self {
+            pool,
+            _snapshot_connection: Mutex::new(snapshot_connection),
+            snapshot_id,
+            snapshot_fingerprint,
+            rate_limiter: RateLimiter::new(options.session.max_queries_per_second),
+        })
+    }
+
+    // export된 스냅샷을 사용하는 읽기 전용 트랜잭션을 시작합니다.
+    async fn begin(&self) -> anyhow::Result<Transaction<'static, Postgres>> {
+        let mut transaction = self.pool.begin().await?;
+
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *transaction)
+            .await?;
+
+        sqlx::query(&format!("SET TRANSACTION SNAPSHOT '{}'", self.snapshot_id))
+            .execute(&mut *transaction)
+            .await?;
+
+        Ok(transaction)
+    }
+}
+
+#[async_trait::async_trait]
+impl SchemaSource for PostgresSource {
+    async fn get_table_list(&self) -> anyhow::Result<Vec<String>> {
+        self.rate_limiter.acquire(1).await;
+
+        let mut transaction = self.begin().await?;
+        let table_list = get_table_list(&mut transaction).await?;
+        transaction.commit().await?;
+
+        Ok(table_list)
+    }
+
+    async fn describe_table(&self, table_name: &str) -> anyhow::Result<Table> {
+        self.rate_limiter.acquire(DESCRIBE_TABLE_QUERY_COUNT).await;
+
+        let mut transaction = self.begin().await?;
+        let table = describe_table(&mut transaction, table_name).await?;
+        transaction.commit().await?;
+
+        Ok(table)
+    }
+
+    async fn describe_database(&self) -> anyhow::Result<Database> {
+        self.rate_limiter.acquire(1).await;
+
+        let mut transaction = self.begin().await?;
+        let database = describe_database(&mut transaction).await?;
+        transaction.commit().await?;
+
+        Ok(database)
+    }
+
+    async fn schema_changed(&self) -> anyhow::Result<bool> {
+        self.rate_limiter.acquire(1).await;
+
+        let mut connection = self.pool.acquire().await?;
+        let fingerprint = get_fingerprint(&mut connection).await?;
+
+        Ok(fingerprint != self.snapshot_fingerprint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TlsOptions;
+
+    fn connect_options(tls: TlsOptions) -> ConnectOptions {
+        ConnectOptions {
+            tls,
+            ..ConnectOptions::default()
+        }
+    }
+
+    #[test]
+    fn tls_mode_to_ssl_mode() {
+        for (mode, ssl_mode) in [
+            (TlsMode::Disable, PgSslMode::Disable),
+            (TlsMode::Prefer, PgSslMode::Prefer),
+            (TlsMode::Require, PgSslMode::Require),
+            (TlsMode::VerifyCa, PgSslMode::VerifyCa),
+            (TlsMode::VerifyFull, PgSslMode::VerifyFull),
+        ] {
+            let tls = TlsOptions {
+                mode: Some(mode),
+                ..TlsOptions::default()
+            };
+
+            // TLS 설정이 URL의 sslmode보다 우선합니다.
+            let options = get_connect_options(
+                "postgres://app@db.internal/prod?sslmode=allow",
+                &connect_options(tls),
+            )
+            .unwrap();
+
+            // PgSslMode는 PartialEq를 구현하지 않으므로 Debug 출력으로 비교합니다.
+            assert_eq!(
+                format!("{:?}", options.get_ssl_mode()),
+                format!("{ssl_mode:?}"),
+                "{mode:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn url_ssl_mode_without_tls_options() {
+        let options = get_connect_options(
+            "postgres://app@db.internal/prod?sslmode=verify-full",
+            &connect_options(TlsOptions::default()),
+        )
+        .unwrap();
+
+        assert!(matches!(options.get_ssl_mode(), PgSslMode::VerifyFull));
+    }
+
+    #[test]
+    fn certificate_paths() {
+        let tls = TlsOptions {
+            mode: Some(TlsMode::VerifyFull),
+            root_cert: Some("/etc/ssl/db/ca.pem".to_string()),
+            client_cert: Some("/etc/ssl/db/client.pem".to_string()),
+            client_key: Some("/etc/ssl/db/client.key".to_string()),
+        };
+
+        let options = get_connect_options(
+            "postgres://app@db.internal/prod?sslrootcert=/tmp/url-ca.pem",
+            &connect_options(tls),
+        )
+        .unwrap();
+
+        // 인증서 경로를 확인하는 getter가 없으므로 Debug 출력으로 확인합니다.
+        let debug = format!("{options:?}");
+        assert!(debug.contains(r#"ssl_root_cert: Some(File("/etc/ssl/db/ca.pem"))"#));
+        assert!(debug.contains(r#"ssl_client_cert: Some(File("/etc/ssl/db/client.pem"))"#));
+        assert!(debug.contains(r#"ssl_client_key: Some(File("/etc/ssl/db/client.key"))"#));
+    }
+}
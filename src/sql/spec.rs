@@ -0,0 +1,528 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::DatabaseType;
+
+use super::{
+    ddl::{normalize_mysql_type, normalize_postgres_type},
+    Column, ForeignKey, Index, Property, SelectColumn, Table,
+};
+
+// 스키마가 반드시 만족해야 하는 조건을 정의하는 명세 파일입니다.
+// 정의하지 않은 속성은 비교하지 않습니다.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SchemaSpec {
+    #[serde(default)]
+    pub tables: Vec<TableSpec>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TableSpec {
+    pub name: String,
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub columns: Vec<ColumnSpec>,
+    #[serde(default)]
+    pub indexes: Vec<IndexSpec>,
+    #[serde(default)]
+    pub foreign_keys: Vec<ForeignKeySpec>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ColumnSpec {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub data_type: Option<String>,
+    pub nullable: Option<bool>,
+    pub default: Option<String>,
+    pub comment: Option<String>,
+    pub auto_increment: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IndexSpec {
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+    pub unique: Option<bool>,
+    pub predicate: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ForeignKeySpec {
+    pub name: Option<String>,
+    pub column: String,
+    // "table.column" 형태
+    pub references: String,
+}
+
+// 확장자에 따라 YAML(.yaml, .yml), TOML(.toml), JSON(.json) 명세 파일을 읽습니다.
+pub fn read_spec_file(database_type: &DatabaseType, path: &str) -> anyhow::Result<Vec<Table>> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "failed to read spec file [{path}], error: {error}"
+            ));
+        }
+    };
+
+    parse_spec(database_type, path, &text)
+}
+
+// path는 형식을 정하는 확장자와 오류 메시지에만 사용합니다.
+pub fn parse_spec(
+    database_type: &DatabaseType,
+    path: &str,
+    text: &str,
+) -> anyhow::Result<Vec<Table>> {
+    let spec: Result<SchemaSpec, anyhow::Error> = if path.ends_with(".toml") {
+        toml::from_str(text).map_err(anyhow::Error::new)
+    } else if path.ends_with(".json") {
+        serde_json::from_str(text).map_err(anyhow::Error::new)
+    } else {
+        serde_norway::from_str(text).map_err(anyhow::Error::new)
+    };
+
+    let spec = match spec {
+        Ok(spec) => spec,
+        Err(error) => {
+            return Err(anyhow::anyhow!(
+                "failed to parse spec file [{path}], error: {error}"
+            ));
+        }
+    };
+
+    match spec.into_tables(database_type) {
+        Ok(tables) => Ok(tables),
+        Err(error) => Err(anyhow::anyhow!("invalid spec file [{path}]: {error}")),
+    }
+}
+
+pub fn is_spec_file(path: &str) -> bool {
+    [".yaml", ".yml", ".toml", ".json"]
+        .iter()
+        .any(|extension| path.ends_with(extension))
+}
+
+impl SchemaSpec {
+    // 명세를 Table 모델로 변환합니다. 정의되지 않은 속성은 unchecked에 기록됩니다.
+    pub fn into_tables(self, database_type: &DatabaseType) -> anyhow::Result<Vec<Table>> {
+        let mut tables = vec![];
+
+        for table_spec in self.tables {
+            let table_name = table_spec.name;
+
+            let mut columns = vec![];
+
+            for column_spec in table_spec.columns {
+                let mut unchecked = vec![];
+
+                let data_type = match column_spec.data_type {
+                    Some(data_type) => match normalize_type(database_type, &data_type) {
+                        Some(data_type) => data_type,
+                        None => {
+                            return Err(anyhow::anyhow!(
+                                "unknown type [{data_type}] of column [{table_name}.{}]{}",
+                                column_spec.name,
+                                match database_type {
+                                    DatabaseType::Postgres =>
+                                        " (write USER-DEFINED for enum and domain types)",
+                                    DatabaseType::Mysql => "",
+                                }
+                            ));
+                        }
+                    },
+                    None => {
+                        unchecked.push(Property::DataType);
+                        String::new()
+                    }
+                };
+
+                if column_spec.nullable.is_none() {
+                    unchecked.push(Property::Nullable);
+                }
+                if column_spec.default.is_none() {
+                    unchecked.push(Property::Default);
+                }
+                if column_spec.comment.is_none() {
+                    unchecked.push(Property::Comment);
+                }
+                if column_spec.auto_increment.is_none() {
+                    unchecked.push(Property::AutoIncrement);
+                }
+
+                columns.push(Column {
+                    name: column_spec.name,
+                    data_type,
+                    default: column_spec.default.unwrap_or_default(),
+                    nullable: column_spec.nullable.unwrap_or(true),
+                    comment: column_spec.comment.unwrap_or_default(),
+                    is_auto_increment: column_spec.auto_increment.unwrap_or(false),
+                    unchecked,
+                });
+            }
+
+            // 컬럼을 정의한 테이블이라면, 인덱스와 외래키는 정의한 컬럼만 사용할 수 있습니다.
+            // 컬럼 없이 인덱스나 외래키만 정의한 테이블은 확인하지 않습니다.
+            let check_column = |column_name: &str, object: &str| {
+                if columns.is_empty() || columns.iter().any(|column| column.name == column_name) {
+                    Ok(())
+                } else {
+                    Err(anyhow::anyhow!(
+                        "{object} of table [{table_name}] uses column [{column_name}], which is not defined in the table"
+                    ))
+                }
+            };
+
+            let mut indexes = vec![];
+
+            for index_spec in table_spec.indexes {
+                if index_spec.columns.is_empty() {
+                    return Err(anyhow::anyhow!(
+                        "index of table [{table_name}] must have at least one column"
+                    ));
+                }
+
+                for column_name in &index_spec.columns {
+                    check_column(column_name, "index")?;
+                }
+
+                let mut unchecked = vec![];
+
+                if index_spec.unique.is_none() {
+                    unchecked.push(Property::Unique);
+                }
+                if index_spec.predicate.is_none() {
+                    unchecked.push(Property::Predicate);
+                }
+
+                indexes.push(Index {
+                    name: index_spec.name.unwrap_or_default(),
+                    columns: index_spec.columns,
+                    predicate: index_spec.predicate.unwrap_or_default(),
+                    is_unique: index_spec.unique.unwrap_or(false),
+                    unchecked,
+                });
+            }
+
+            let mut constraints = vec![];
+
+            for foreign_key_spec in table_spec.foreign_keys {
+                let Some((foreign_table_name, foreign_column_name)) =
+                    foreign_key_spec.references.split_once('.')
+                else {
+                    return Err(anyhow::anyhow!(
+                        "foreign key of table [{table_name}] must reference \"table.column\", but got [{}]",
+                        foreign_key_spec.references
+                    ));
+                };
+
+                check_column(&foreign_key_spec.column, "foreign key")?;
+
+                constraints.push(
+                    ForeignKey {
+                        name: foreign_key_spec.name.unwrap_or_default(),
+                        column: vec![foreign_key_spec.column],
+                        foreign_column: SelectColumn {
+                            table_name: foreign_table_name.to_string(),
+                            column_name: foreign_column_name.to_string(),
+                        },
+                    }
+                    .into(),
+                );
+            }
+
+            let mut unchecked = vec![];
+
+            if table_spec.comment.is_none() {
+                unchecked.push(Property::Comment);
+            }
+
+            tables.push(Table {
+                name: table_name,
+                comment: table_spec.comment.unwrap_or_default(),
+                columns,
+                indexes,
+                constraints,
+                unchecked,
+            });
+        }
+
+        check_references(&tables)?;
+
+        Ok(tables)
+    }
+}
+
+// 외래키가 참조하는 테이블이 명세에 있고 컬럼을 정의했다면, 참조하는 컬럼도 정의되어 있어야 합니다.
+fn check_references(tables: &[Table]) -> anyhow::Result<()> {
+    for table in tables {
+        for foreign_key in table.foreign_keys() {
+            let reference = &foreign_key.foreign_column;
+
+            let Some(foreign_table) = tables
+                .iter()
+                .find(|table| table.name == reference.table_name)
+            else {
+                continue;
+            };
+
+            if !foreign_table.columns.is_empty()
+                && !foreign_table
+                    .columns
+                    .iter()
+                    .any(|column| column.name == reference.column_name)
+            {
+                return Err(anyhow::anyhow!(
+                    "foreign key of table [{}] references [{}.{}], which is not defined in the spec",
+                    table.name,
+                    reference.table_name,
+                    reference.column_name
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// information_schema.columns.column_type의 타입 이름 (mysql::describe_table 참조)
+const MYSQL_TYPES: [&str; 38] = [
+    "tinyint",
+    "smallint",
+    "mediumint",
+    "int",
+    "bigint",
+    "decimal",
+    "float",
+    "double",
+    "real",
+    "bit",
+    "date",
+    "datetime",
+    "timestamp",
+    "time",
+    "year",
+    "char",
+    "varchar",
+    "binary",
+    "varbinary",
+    "tinyblob",
+    "blob",
+    "mediumblob",
+    "longblob",
+    "tinytext",
+    "text",
+    "mediumtext",
+    "longtext",
+    "enum",
+    "set",
+    "json",
+    "geometry",
+    "point",
+    "linestring",
+    "polygon",
+    "multipoint",
+    "multilinestring",
+    "multipolygon",
+    "geometrycollection",
+];
+
+// 명세의 타입을 조회 결과와 같은 형태로 정규화합니다. 알 수 없는 타입이면 None을 반환합니다.
+fn normalize_type(database_type: &DatabaseType, data_type: &str) -> Option<String> {
+    match database_type {
+        DatabaseType::Postgres => {
+            // enum, domain 등은 information_schema.columns.data_type처럼 USER-DEFINED로 적습니다.
+            if data_type.eq_ignore_ascii_case("user-defined") {
+                return Some("USER-DEFINED".to_string());
+            }
+            if data_type.eq_ignore_ascii_case("array") {
+                return Some("ARRAY".to_string());
+            }
+
+            let normalized = normalize_postgres_type(data_type);
+
+            (normalized != "USER-DEFINED").then_some(normalized)
+        }
+        DatabaseType::Mysql => {
+            let normalized = normalize_mysql_type(data_type);
+            let base = normalized.split(['(', ' ']).next().unwrap_or_default();
+
+            MYSQL_TYPES.contains(&base).then_some(normalized)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::check::{difference_check, CheckOptions};
+    use crate::sql::Constraint;
+
+    // 같은 명세를 세 가지 형식으로 적은 파일 (src/sql/testdata/spec.*)
+    const SPEC_YAML: &str = include_str!("testdata/spec.yaml");
+    const SPEC_TOML: &str = include_str!("testdata/spec.toml");
+    const SPEC_JSON: &str = include_str!("testdata/spec.json");
+
+    fn parse(path: &str, text: &str) -> anyhow::Result<Vec<Table>> {
+        parse_spec(&DatabaseType::Postgres, path, text)
+    }
+
+    fn table<'a>(tables: &'a [Table], name: &str) -> &'a Table {
+        tables
+            .iter()
+            .find(|table| table.name == name)
+            .unwrap_or_else(|| panic!("table {name} is not parsed"))
+    }
+
+    #[test]
+    fn yaml_spec() {
+        let tables = parse("spec.yaml", SPEC_YAML).unwrap();
+
+        let users = table(&tables, "users");
+        assert_eq!(users.comment, "login accounts");
+        assert!(users.unchecked.is_empty());
+
+        let email = &users.columns[1];
+        assert_eq!(email.name, "email");
+        assert_eq!(email.data_type, "varchar(320)");
+        assert!(!email.nullable);
+        assert_eq!(
+            email.unchecked,
+            [
+                Property::Default,
+                Property::Comment,
+                Property::AutoIncrement
+            ]
+        );
+        assert_eq!(users.columns[2].data_type, "USER-DEFINED");
+
+        assert_eq!(users.indexes[0].name, "users_email_key");
+        assert!(users.indexes[0].is_unique);
+        assert_eq!(users.indexes[0].unchecked, [Property::Predicate]);
+
+        // 컬럼 없이 인덱스와 외래키만 정의한 테이블
+        let orders = table(&tables, "orders");
+        assert!(orders.columns.is_empty());
+        assert_eq!(orders.unchecked, [Property::Comment]);
+        assert_eq!(orders.indexes[0].name, "");
+        assert_eq!(orders.indexes[0].columns, ["customer_id", "created_at"]);
+        assert_eq!(
+            orders.indexes[0].unchecked,
+            [Property::Unique, Property::Predicate]
+        );
+
+        let foreign_key = orders.foreign_keys()[0];
+        assert_eq!(foreign_key.column, ["user_id"]);
+        assert_eq!(foreign_key.foreign_column.table_name, "users");
+        assert_eq!(foreign_key.foreign_column.column_name, "id");
+    }
+
+    #[test]
+    fn toml_and_json_specs_match_yaml() {
+        let yaml = format!("{:?}", parse("spec.yaml", SPEC_YAML).unwrap());
+
+        assert_eq!(
+            format!("{:?}", parse("spec.toml", SPEC_TOML).unwrap()),
+            yaml
+        );
+        assert_eq!(
+            format!("{:?}", parse("spec.json", SPEC_JSON).unwrap()),
+            yaml
+        );
+    }
+
+    #[test]
+    fn unchecked_properties_are_not_compared() {
+        let spec_tables = parse("spec.yaml", SPEC_YAML).unwrap();
+
+        // 명세에 적지 않은 속성(기본값, 코멘트, 인덱스 이름 등)만 다른 데이터베이스
+        let mut users = table(&spec_tables, "users").clone();
+        for column in users.columns.iter_mut() {
+            column.default = "'x'".to_string();
+            column.comment = "undocumented".to_string();
+            column.is_auto_increment = true;
+        }
+        users.columns[2].nullable = false;
+        users.indexes[0].predicate = "(email IS NOT NULL)".to_string();
+
+        let mut orders = table(&spec_tables, "orders").clone();
+        orders.comment = "orders".to_string();
+        orders.indexes[0].name = "orders_customer_id_created_at_idx".to_string();
+        orders.indexes[0].is_unique = true;
+        let Constraint::ForeignKey(foreign_key) = &mut orders.constraints[0];
+        foreign_key.name = "orders_user_id_fkey".to_string();
+        for (name, data_type) in [("customer_id", "bigint"), ("created_at", "timestamp")] {
+            orders.columns.push(Column {
+                name: name.to_string(),
+                data_type: data_type.to_string(),
+                ..Default::default()
+            });
+        }
+
+        let report = difference_check(
+            &CheckOptions::default(),
+            spec_tables
+                .into_iter()
+                .map(|table| (table.name.clone(), table))
+                .collect(),
+            HashMap::from([("users".to_string(), users), ("orders".to_string(), orders)]),
+            |_, _| {},
+        );
+
+        assert_eq!(
+            report
+                .findings()
+                .map(|finding| finding.fingerprint())
+                .collect::<Vec<_>>(),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn unknown_type_is_rejected() {
+        let spec =
+            "tables:\n  - name: users\n    columns:\n      - name: email\n        type: strng\n";
+
+        let error = parse("spec.yaml", spec).unwrap_err().to_string();
+        assert!(error.contains("unknown type [strng] of column [users.email]"));
+
+        let error = parse_spec(&DatabaseType::Mysql, "spec.yaml", spec)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unknown type [strng]"));
+
+        let spec = "tables:\n  - name: users\n    columns:\n      - name: email\n        type: VARCHAR(320)\n";
+        let tables = parse_spec(&DatabaseType::Mysql, "spec.yaml", spec).unwrap();
+        assert_eq!(tables[0].columns[0].data_type, "varchar(320)");
+    }
+
+    #[test]
+    fn missing_column_is_rejected() {
+        let index = r#"{"tables": [{"name": "users", "columns": [{"name": "id"}], "indexes": [{"columns": ["email"]}]}]}"#;
+        let error = parse("spec.json", index).unwrap_err().to_string();
+        assert!(error.contains("index of table [users] uses column [email]"));
+
+        let foreign_key = r#"{"tables": [{"name": "orders", "columns": [{"name": "id"}], "foreign_keys": [{"column": "user_id", "references": "users.id"}]}]}"#;
+        let error = parse("spec.json", foreign_key).unwrap_err().to_string();
+        assert!(error.contains("foreign key of table [orders] uses column [user_id]"));
+
+        let reference = r#"{"tables": [
+            {"name": "users", "columns": [{"name": "user_id"}]},
+            {"name": "orders", "foreign_keys": [{"column": "user_id", "references": "users.id"}]}
+        ]}"#;
+        let error = parse("spec.json", reference).unwrap_err().to_string();
+        assert!(error.contains("references [users.id], which is not defined in the spec"));
+    }
+
+    #[test]
+    fn invalid_spec_file() {
+        assert!(parse("spec.yaml", "tables: [").is_err());
+        assert!(parse(
+            "spec.json",
+            r#"{"tables": [{"name": "t", "foreign_keys": [{"column": "a", "references": "b"}]}]}"#
+        )
+        .is_err());
+    }
+}